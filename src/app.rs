@@ -31,6 +31,7 @@ pub struct App {
     // Runtime
     pub app_config_home: PathBuf,
     pub app_data_home:   PathBuf,
+    pub app_state_home:  PathBuf,
     pub app_cache_home:  PathBuf,
 }
 
@@ -61,11 +62,13 @@ impl Default for App {
         // XDG
         let mut xdg_config_home   = home_dir.join(".config");      // ~/.var/app/<APP_ID>/config
         let mut xdg_data_home     = home_dir.join(".local/share"); // ~/.var/app/<APP_ID>/data
+        let mut xdg_state_home    = home_dir.join(".local/state"); // ~/.var/app/<APP_ID>/.local/state
         let mut xdg_cache_home    = home_dir.join(".cache");       // ~/.var/app/<APP_ID>/cache
 
         // Flatpak
         if let Ok(var) = env::var("XDG_CONFIG_HOME") { xdg_config_home = Path::new(&var).into(); }
         if let Ok(var) = env::var("XDG_DATA_HOME") { xdg_data_home = Path::new(&var).into(); }
+        if let Ok(var) = env::var("XDG_STATE_HOME") { xdg_state_home = Path::new(&var).into(); }
         if let Ok(var) = env::var("XDG_CACHE_HOME") { xdg_cache_home = Path::new(&var).into(); }
 
         App {
@@ -85,6 +88,7 @@ impl Default for App {
             // Runtime
             app_config_home: xdg_config_home.join(command_name),
             app_data_home:   xdg_data_home.join(command_name),
+            app_state_home:  xdg_state_home.join(command_name),
             app_cache_home:  xdg_cache_home.join(command_name),
         }
     }
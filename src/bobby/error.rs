@@ -0,0 +1,173 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error;
+use std::fmt;
+use std::io;
+use std::path::{ Path, PathBuf };
+
+use rusqlite::ErrorCode;
+
+
+#[derive(Debug)]
+pub enum Error {
+    /// The file went away or never existed
+    NotFound(PathBuf),
+
+    /// No read access to the file or its directory
+    PermissionDenied(PathBuf, io::Error),
+
+    /// Another process holds a lock that doesn't let us read. Without
+    /// a path when it happened after opening, while browsing.
+    Locked(Option<PathBuf>, rusqlite::Error),
+
+    /// Not a SQLite file, or an encrypted one
+    NotADatabase(PathBuf),
+
+    /// The database disk image is malformed, found while opening or browsing
+    Corrupt(Option<PathBuf>, rusqlite::Error),
+
+    /// Something in the schema we don't know how to browse
+    UnsupportedSchema(String),
+
+    /// Any other failure while opening the connection
+    Open(PathBuf, rusqlite::Error),
+
+    /// Any other failure while browsing
+    Sqlite(rusqlite::Error),
+
+    Gio(gio::glib::Error),
+    Io(io::Error),
+
+    /// The GIO file has no local path
+    MissingPath,
+}
+
+
+impl Error {
+    /// Classifies a failure of opening or first reading the file at `path`
+    pub fn from_open(path: PathBuf, e: rusqlite::Error) -> Self {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::DatabaseBusy) |
+            Some(ErrorCode::DatabaseLocked) |
+            Some(ErrorCode::FileLockingProtocolFailed) => Self::Locked(Some(path), e),
+
+            Some(ErrorCode::NotADatabase) => Self::NotADatabase(path),
+            Some(ErrorCode::DatabaseCorrupt) => Self::Corrupt(Some(path), e),
+
+            Some(ErrorCode::PermissionDenied) => Self::PermissionDenied(
+                path,
+                io::Error::from(io::ErrorKind::PermissionDenied),
+            ),
+
+            _ => Self::Open(path, e),
+        }
+    }
+
+
+    /// Classifies a failure of reading the metadata of the file at `path`
+    pub fn from_metadata(path: PathBuf, e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::NotFound => Self::NotFound(path),
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied(path, e),
+            _ => Self::Io(e),
+        }
+    }
+
+
+    /// A short title for the error state page
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::NotFound(_) => "File Not Found",
+            Self::PermissionDenied(_, _) => "No Permission to Read File",
+            Self::Locked(_, _) => "File Is Locked",
+            Self::NotADatabase(_) => "Not a Database",
+            Self::Corrupt(_, _) => "File Is Damaged",
+            Self::UnsupportedSchema(_) => "Unsupported Database",
+            _ => "Unable to Open File",
+        }
+    }
+
+
+    /// Whether trying again later could succeed
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Locked(_, _) | Self::NotFound(_) | Self::Io(_))
+    }
+}
+
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(path) =>
+                write!(f, "{} does not exist", file_name(path)),
+            Self::PermissionDenied(path, _) =>
+                write!(f, "{} is not readable", file_name(path)),
+            Self::Locked(Some(path), e) =>
+                write!(f, "{} is locked by another program: {e}", file_name(path)),
+            Self::Locked(None, e) =>
+                write!(f, "The database is locked by another program: {e}"),
+            Self::NotADatabase(path) =>
+                write!(f, "{} is not a SQLite database or is encrypted", file_name(path)),
+            Self::Corrupt(Some(path), e) =>
+                write!(f, "{} is damaged: {e}", file_name(path)),
+            Self::Corrupt(None, e) =>
+                write!(f, "The database is damaged: {e}"),
+            Self::UnsupportedSchema(s) =>
+                write!(f, "Unsupported schema: {s}"),
+            Self::Open(path, e) =>
+                write!(f, "Could not open {}: {e}", file_name(path)),
+            Self::Sqlite(e) => write!(f, "{e}"),
+            Self::Gio(e) => write!(f, "{e}"),
+            Self::Io(e) => write!(f, "{e}"),
+            Self::MissingPath => write!(f, "File has no local path"),
+        }
+    }
+}
+
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::PermissionDenied(_, e) | Self::Io(e) => Some(e),
+            Self::Locked(_, e) | Self::Corrupt(_, e) | Self::Open(_, e) | Self::Sqlite(e) => Some(e),
+            Self::Gio(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or("Database".into())
+}
+
+
+impl From<rusqlite::Error> for Error {
+    fn from(e: rusqlite::Error) -> Self {
+        match e.sqlite_error_code() {
+            Some(ErrorCode::DatabaseBusy) |
+            Some(ErrorCode::DatabaseLocked) => Self::Locked(None, e),
+            Some(ErrorCode::DatabaseCorrupt) => Self::Corrupt(None, e),
+            _ => Self::Sqlite(e),
+        }
+    }
+}
+
+impl From<gio::glib::Error> for Error {
+    fn from(e: gio::glib::Error) -> Self {
+        Self::Gio(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
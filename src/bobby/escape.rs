@@ -0,0 +1,35 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+/// Lets text with tabs and newlines, like table names
+/// and paths, be a field in a tab separated line
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+pub fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
@@ -0,0 +1,292 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::str;
+
+use rusqlite::types::{ Value, ValueRef };
+
+use crate::bobby::Error;
+use crate::bobby::sqlite::column::Column;
+use crate::bobby::sqlite::database::Database;
+use crate::bobby::sqlite::table::Table;
+
+use super::encoding::hex;
+use super::json::{ json_string, json_value };
+use super::sql::{ sql_identifier, sql_literal };
+
+
+/// How a single row is put on the clipboard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CopyFormat {
+    Json,
+    Insert,
+    Update,
+    Where,
+}
+
+impl str::FromStr for CopyFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json"   => Ok(Self::Json),
+            "insert" => Ok(Self::Insert),
+            "update" => Ok(Self::Update),
+            "where"  => Ok(Self::Where),
+            _ => Err(format!("Unknown copy format: {s}")),
+        }
+    }
+}
+
+
+impl CopyFormat {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Json   => "JSON",
+            Self::Insert => "INSERT statement",
+            Self::Update => "UPDATE statement",
+            Self::Where  => "WHERE clause",
+        }
+    }
+}
+
+
+/// How the values of a whole column are put on the clipboard
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ValuesFormat {
+    Lines,
+    InList,
+}
+
+impl str::FromStr for ValuesFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lines" => Ok(Self::Lines),
+            "in"    => Ok(Self::InList),
+            _ => Err(format!("Unknown values format: {s}")),
+        }
+    }
+}
+
+
+/// The columns and values that single out a row
+pub type RowKey = Vec<(String, Value)>;
+
+/// The rowid, if the table has one, and the full values of a row
+type RowValues = (Option<i64>, Vec<Value>);
+
+
+impl Database {
+    /// One line per row, for the rows at runs of (first position, number of rows).
+    /// Reads the full values, as cached rows only hold a preview of BLOBs.
+    pub fn copy_rows_as(&self, table: &Table, runs: &[(u32, u32)], format: CopyFormat) -> Result<Vec<String>, Error> {
+        let columns = self.columns(table)?;
+
+        let names = columns
+            .iter()
+            .map(|column| (column.name.clone(), column.primary_key))
+            .collect::<Vec<_>>();
+
+        let all = columns.iter().map(|column| column.name.clone()).collect::<Vec<_>>();
+        let table_name = table.name();
+
+        let mut lines = Vec::new();
+
+        for (rowid, values) in self.values_at(table, &all, runs)? {
+            let key = row_key(&names, &values, rowid);
+
+            // Generated columns can't be written to
+            let (writable, writable_values): (Vec<_>, Vec<_>) = columns
+                .iter()
+                .zip(&values)
+                .filter(|(column, _)| !column.generated)
+                .map(|(column, value)| (column.name.clone(), value.clone()))
+                .unzip();
+
+            lines.push(match format {
+                CopyFormat::Json   => row_as_json(&all, &values),
+                CopyFormat::Insert => row_as_insert(&table_name, &writable, &writable_values),
+                CopyFormat::Update => row_as_update(&table_name, &writable, &writable_values, &key),
+                CopyFormat::Where  => row_as_where(&key),
+            });
+        }
+
+        Ok(lines)
+    }
+
+
+    /// Reads every value in `column` in the configured row order, or the
+    /// first occurrence of each value when `distinct` is set
+    pub fn column_values(&self, table: &Table, column: &Column, distinct: bool) -> Result<Vec<Value>, Error> {
+        let table_name = sql_identifier(&table.name());
+        let name = sql_identifier(&column.name);
+        let row_order = self.row_order.unwrap_or_default();
+
+        let sql = match (table.has_row_id(), distinct) {
+            (Some(true), true) => format!("SELECT {name} FROM {table_name} GROUP BY {name} ORDER BY MIN(rowid) {row_order};"),
+            (Some(true), false) => format!("SELECT {name} FROM {table_name} ORDER BY rowid {row_order};"),
+            (_, true) => format!("SELECT DISTINCT {name} FROM {table_name};"),
+            (_, false) => format!("SELECT {name} FROM {table_name};"),
+        };
+
+        let connection = self.connection.borrow();
+        let mut sql = connection.prepare(&sql)?;
+
+        let values = sql
+            .query_map([], |row| row.get::<_, Value>(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(values)
+    }
+
+
+    /// The rowid and the values of the named `columns` of the rows at `runs`,
+    /// in the configured order, with a query per run
+    fn values_at(
+        &self,
+        table: &Table,
+        columns: &[String],
+        runs: &[(u32, u32)],
+    ) -> Result<Vec<RowValues>, Error>
+    {
+        let table_name = sql_identifier(&table.name());
+        let row_order = self.row_order.unwrap_or_default();
+        let names = columns.iter().map(|name| sql_identifier(name)).collect::<Vec<_>>().join(", ");
+        let with_row_id = table.has_row_id() == Some(true);
+
+        let sql = match with_row_id {
+            true => format!("SELECT rowid, {names} FROM {table_name} ORDER BY rowid {row_order} LIMIT ?1 OFFSET ?2;"),
+            false => format!("SELECT {names} FROM {table_name} LIMIT ?1 OFFSET ?2;"),
+        };
+
+        let connection = self.connection.borrow();
+        let mut sql = connection.prepare(&sql)?;
+        let first_column = usize::from(with_row_id);
+
+        let mut rows = Vec::new();
+
+        for (first, n) in runs {
+            let found = sql.query_map([n, first], |row| {
+                let rowid = match with_row_id {
+                    true => Some(row.get::<_, i64>(0)?),
+                    false => None,
+                };
+
+                let values = (first_column..first_column + columns.len())
+                    .map(|i| row.get::<_, Value>(i))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok((rowid, values))
+            })?;
+
+            for row in found {
+                rows.push(row?);
+            }
+        }
+
+        Ok(rows)
+    }
+}
+
+
+/// Prefers the primary key, as rowids can change when the database is vacuumed,
+/// and falls back to every column for views
+pub fn row_key(names: &[(String, bool)], values: &[Value], rowid: Option<i64>) -> RowKey {
+    let columns = names.iter().zip(values);
+
+    let primary_key = columns.clone()
+        .filter(|((_, primary_key), _)| *primary_key)
+        .map(|((name, _), value)| (name.clone(), value.clone()))
+        .collect::<Vec<_>>();
+
+    match rowid {
+        _ if !primary_key.is_empty() => primary_key,
+        Some(rowid) => vec![("rowid".to_string(), Value::Integer(rowid))],
+        None => columns
+            .map(|((name, _), value)| (name.clone(), value.clone()))
+            .collect(),
+    }
+}
+
+
+pub fn row_as_json(columns: &[String], values: &[Value]) -> String {
+    let members = columns.iter()
+        .zip(values)
+        .map(|(name, value)| format!("{}: {}", json_string(name), json_value(ValueRef::from(value))))
+        .collect::<Vec<_>>();
+
+    format!("{{{}}}", members.join(", "))
+}
+
+
+pub fn row_as_insert(table_name: &str, columns: &[String], values: &[Value]) -> String {
+    format!(
+        "INSERT INTO {} ({}) VALUES ({});",
+        sql_identifier(table_name),
+        columns.iter().map(|name| sql_identifier(name)).collect::<Vec<_>>().join(", "),
+        values.iter().map(|value| sql_literal(ValueRef::from(value))).collect::<Vec<_>>().join(", "),
+    )
+}
+
+
+/// Sets every column that isn't part of the key to its current value, ready for editing
+pub fn row_as_update(table_name: &str, columns: &[String], values: &[Value], key: &RowKey) -> String {
+    let assignments = columns.iter()
+        .zip(values)
+        .filter(|(name, _)| key.len() == columns.len() || !key.iter().any(|(k, _)| k == *name))
+        .map(|(name, value)| format!("{} = {}", sql_identifier(name), sql_literal(ValueRef::from(value))))
+        .collect::<Vec<_>>();
+
+    format!(
+        "UPDATE {} SET {} {};",
+        sql_identifier(table_name),
+        assignments.join(", "),
+        row_as_where(key),
+    )
+}
+
+
+pub fn row_as_where(key: &RowKey) -> String {
+    let conditions = key.iter()
+        .map(|(name, value)| match value {
+            Value::Null => format!("{} IS NULL", sql_identifier(name)),
+            value => format!("{} = {}", sql_identifier(name), sql_literal(ValueRef::from(value))),
+        })
+        .collect::<Vec<_>>();
+
+    format!("WHERE {}", conditions.join(" AND "))
+}
+
+
+/// One value per line, with NULLs as empty lines
+pub fn values_as_lines(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(|value| match value {
+            Value::Null       => String::new(),
+            Value::Integer(i) => i.to_string(),
+            Value::Real(f)    => f.to_string(),
+            Value::Text(s)    => s.clone(),
+            Value::Blob(b)    => hex(b),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+
+/// Leaves out NULLs, as they never match in a list
+pub fn values_as_in_list(values: &[Value]) -> String {
+    let literals = values
+        .iter()
+        .filter(|value| !matches!(value, Value::Null))
+        .map(|value| sql_literal(ValueRef::from(value)))
+        .collect::<Vec<_>>();
+
+    format!("IN ({})", literals.join(", "))
+}
@@ -0,0 +1,109 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io::Write;
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::Error;
+use crate::bobby::sqlite::database::Database;
+use crate::bobby::sqlite::table::Table;
+
+use super::encoding::{ BlobEncoding, NullEncoding };
+
+
+#[derive(Clone, Debug)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub null: NullEncoding,
+    pub blob: BlobEncoding,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            null: NullEncoding::default(),
+            blob: BlobEncoding::default(),
+        }
+    }
+}
+
+
+/// Docs: https://www.rfc-editor.org/rfc/rfc4180
+impl Database {
+    /// Writes a header line and up to `limit` rows
+    pub fn export_csv(
+        &self,
+        table: &Table,
+        writer: &mut impl Write,
+        options: &CsvOptions,
+        limit: Option<u64>,
+    ) -> Result<u64, Error>
+    {
+        let header = self.columns(table)?
+            .iter()
+            .map(|column| csv_field(&column.name, options.delimiter))
+            .collect::<Vec<_>>();
+
+        write_csv_line(writer, &header, options.delimiter)?;
+
+        self.for_each_row_limit(table, limit, |row, n_columns| {
+            write_csv_line(writer, &csv_fields(row, n_columns, options)?, options.delimiter)
+        })
+    }
+}
+
+
+pub fn csv_fields(
+    row: &rusqlite::Row,
+    n_columns: usize,
+    options: &CsvOptions,
+) -> Result<Vec<String>, Error>
+{
+    let mut fields = Vec::with_capacity(n_columns);
+
+    for i in 0..n_columns {
+        let field = match row.get_ref(i)? {
+            ValueRef::Null       => options.null.encode().to_string(),
+            ValueRef::Integer(i) => i.to_string(),
+            ValueRef::Real(f)    => f.to_string(),
+            ValueRef::Text(t)    => String::from_utf8_lossy(t).to_string(),
+            ValueRef::Blob(b)    => options.blob.encode(b),
+        };
+
+        fields.push(csv_field(&field, options.delimiter));
+    }
+
+    Ok(fields)
+}
+
+
+/// Quotes a field if it contains the delimiter, quotes or line breaks
+pub fn csv_field(s: &str, delimiter: char) -> String {
+    let needs_quotes = s.contains(delimiter) ||
+        s.contains(['"', '\r', '\n']);
+
+    if needs_quotes {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+
+pub fn write_csv_line(
+    writer: &mut impl Write,
+    fields: &[String],
+    delimiter: char,
+) -> Result<(), Error>
+{
+    let line = fields.join(&delimiter.to_string());
+    write!(writer, "{line}\r\n")?;
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::str;
+
+
+/// How NULL is written to formats without a native NULL
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NullEncoding {
+    #[default]
+    Empty,
+    Null,
+}
+
+impl str::FromStr for NullEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "empty" => Ok(Self::Empty),
+            "null"  => Ok(Self::Null),
+            _ => Err("Invalid NULL encoding".into())
+        }
+    }
+}
+
+impl NullEncoding {
+    pub fn encode(&self) -> &'static str {
+        match self {
+            Self::Empty => "",
+            Self::Null  => "NULL",
+        }
+    }
+}
+
+
+/// How BLOBs are written to text based formats
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BlobEncoding {
+    Empty,
+    #[default]
+    Base64,
+    Hex,
+}
+
+impl str::FromStr for BlobEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "empty"  => Ok(Self::Empty),
+            "base64" => Ok(Self::Base64),
+            "hex"    => Ok(Self::Hex),
+            _ => Err("Invalid BLOB encoding".into())
+        }
+    }
+}
+
+impl BlobEncoding {
+    pub fn encode(&self, blob: &[u8]) -> String {
+        match self {
+            Self::Empty  => String::new(),
+            Self::Base64 => base64(blob),
+            Self::Hex    => hex(blob),
+        }
+    }
+}
+
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Docs: https://www.rfc-editor.org/rfc/rfc4648#section-4
+pub fn base64(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (n >> (18 - 6 * i)) & 0x3F;
+                s.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02X}", b))
+        .collect()
+}
@@ -0,0 +1,79 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::Path;
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+    Ndjson,
+    Sql,
+    Ods,
+}
+
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 5] = [
+        Self::Csv,
+        Self::Json,
+        Self::Ndjson,
+        Self::Sql,
+        Self::Ods,
+    ];
+
+
+    /// Picks the format from the file extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_string_lossy().to_lowercase();
+
+        Self::ALL
+            .into_iter()
+            .find(|format| format.extension() == extension)
+    }
+
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv    => "csv",
+            Self::Json   => "json",
+            Self::Ndjson => "ndjson",
+            Self::Sql    => "sql",
+            Self::Ods    => "ods",
+        }
+    }
+
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Csv    => "CSV",
+            Self::Json   => "JSON",
+            Self::Ndjson => "Newline-Delimited JSON",
+            Self::Sql    => "SQL Dump",
+            Self::Ods    => "OpenDocument Spreadsheet",
+        }
+    }
+
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Csv    => "text/csv",
+            Self::Json   => "application/json",
+            Self::Ndjson => "application/x-ndjson",
+            Self::Sql    => "application/sql",
+            Self::Ods    => "application/vnd.oasis.opendocument.spreadsheet",
+        }
+    }
+
+
+    /// Whether it can hold several tables at once
+    pub fn supports_database(&self) -> bool {
+        matches!(self, Self::Sql | Self::Ods)
+    }
+}
@@ -0,0 +1,119 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io::Write;
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::Error;
+use crate::bobby::sqlite::database::Database;
+use crate::bobby::sqlite::table::Table;
+
+use super::encoding::base64;
+
+
+/// Docs: https://www.rfc-editor.org/rfc/rfc8259
+///       https://github.com/ndjson/ndjson-spec
+impl Database {
+    /// Writes up to `limit` rows as an array of objects keyed by column name
+    pub fn export_json(
+        &self,
+        table: &Table,
+        writer: &mut impl Write,
+        limit: Option<u64>,
+    ) -> Result<u64, Error>
+    {
+        let mut keys = Vec::new();
+        let mut first = true;
+
+        write!(writer, "[")?;
+
+        let count = self.for_each_row_limit(table, limit, |row, n_columns| {
+            let separator = if first { "" } else { "," };
+            first = false;
+
+            if keys.is_empty() {
+                keys = json_keys(row, n_columns)?;
+            }
+
+            write!(writer, "{separator}\n  {}", json_object(&keys, row)?)?;
+            Ok(())
+        })?;
+
+        write!(writer, "\n]\n")?;
+        Ok(count)
+    }
+
+
+    /// Writes the table as one object per line
+    pub fn export_ndjson(&self, table: &Table, writer: &mut impl Write) -> Result<u64, Error> {
+        let mut keys = Vec::new();
+
+        self.for_each_row(table, |row, n_columns| {
+            if keys.is_empty() {
+                keys = json_keys(row, n_columns)?;
+            }
+
+            writeln!(writer, "{}", json_object(&keys, row)?)?;
+            Ok(())
+        })
+    }
+}
+
+
+/// The names of the columns of the query that `row` comes from,
+/// so that keys always line up with the values they're paired with
+pub fn json_keys(row: &rusqlite::Row, n_columns: usize) -> Result<Vec<String>, Error> {
+    (0..n_columns)
+        .map(|i| Ok(json_string(row.as_ref().column_name(i)?)))
+        .collect()
+}
+
+
+pub fn json_object(keys: &[String], row: &rusqlite::Row) -> Result<String, Error> {
+    let mut members = Vec::with_capacity(keys.len());
+
+    for (i, key) in keys.iter().enumerate() {
+        members.push(format!("{key}: {}", json_value(row.get_ref(i)?)));
+    }
+
+    Ok(format!("{{{}}}", members.join(", ")))
+}
+
+
+/// Uses the storage class of the value, rather than the declared type
+pub fn json_value(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null       => "null".to_string(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) if f.is_finite() => format!("{f:?}"), // Keeps the ".0"
+        ValueRef::Real(_)    => "null".to_string(), // No NaN or Infinity in JSON
+        ValueRef::Text(t)    => json_string(&String::from_utf8_lossy(t)),
+        ValueRef::Blob(b)    => format!("{{\"base64\": \"{}\"}}", base64(b)),
+    }
+}
+
+
+pub fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+
+    for c in s.chars() {
+        match c {
+            '"'  => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
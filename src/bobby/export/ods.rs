@@ -0,0 +1,277 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io::{ Seek, Write };
+
+use chrono::{ NaiveDate, NaiveDateTime };
+use rusqlite::types::ValueRef;
+
+use crate::bobby::Error;
+use crate::bobby::sqlite::database::Database;
+use crate::bobby::sqlite::table::Table;
+
+use super::encoding::BlobEncoding;
+use super::zip::ZipWriter;
+
+
+const MIME_TYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+const NAMESPACES: &str = concat!(
+    r#"xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" "#,
+    r#"xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" "#,
+    r#"xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" "#,
+    r#"xmlns:style="urn:oasis:names:tc:opendocument:xmlns:style:1.0" "#,
+    r#"xmlns:number="urn:oasis:names:tc:opendocument:xmlns:datastyle:1.0" "#,
+    r#"xmlns:fo="urn:oasis:names:tc:opendocument:xmlns:xsl-fo-compatible:1.0" "#,
+    r#"xmlns:config="urn:oasis:names:tc:opendocument:xmlns:config:1.0" "#,
+    r#"office:version="1.3""#,
+);
+
+const STYLES: &str = r#"<office:automatic-styles>
+<number:date-style style:name="N1"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/></number:date-style>
+<number:date-style style:name="N2"><number:year number:style="long"/><number:text>-</number:text><number:month number:style="long"/><number:text>-</number:text><number:day number:style="long"/><number:text> </number:text><number:hours number:style="long"/><number:text>:</number:text><number:minutes number:style="long"/><number:text>:</number:text><number:seconds number:style="long"/></number:date-style>
+<style:style style:name="header" style:family="table-cell"><style:text-properties fo:font-weight="bold"/></style:style>
+<style:style style:name="date" style:family="table-cell" style:data-style-name="N1"/>
+<style:style style:name="datetime" style:family="table-cell" style:data-style-name="N2"/>
+</office:automatic-styles>
+"#;
+
+/// Integers beyond this would be rounded by spreadsheets, which store doubles
+const MAX_SAFE_INTEGER: i64 = 1 << 53;
+
+
+/// Docs: https://docs.oasis-open.org/office/OpenDocument/v1.3/
+impl Database {
+    /// Writes `table`, or every table in the database when `None`,
+    /// as a sheet each with a frozen header row
+    pub fn export_ods(
+        &self,
+        table: Option<&Table>,
+        writer: &mut (impl Write + Seek),
+        blob: BlobEncoding,
+    ) -> Result<u64, Error>
+    {
+        let tables = match table {
+            Some(table) => vec![table.clone()],
+            None => self.tables()?.into_iter().filter(|t| !t.is_view()).collect(),
+        };
+
+        let mut sheet_names: Vec<String> = Vec::new();
+
+        // The mimetype must come first and uncompressed, so that it can be sniffed
+        let mut zip = ZipWriter::new(writer);
+        zip.add("mimetype", MIME_TYPE.as_bytes())?;
+
+        let count = zip.add_with("content.xml", |content| {
+            let mut count = 0;
+
+            writeln!(content, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+            writeln!(content, "<office:document-content {NAMESPACES}>")?;
+            content.write_all(STYLES.as_bytes())?;
+            writeln!(content, "<office:body><office:spreadsheet>")?;
+
+            for table in &tables {
+                let columns = self.columns(table)?;
+                let sheet_name = ods_sheet_name(&table.name(), &sheet_names);
+
+                writeln!(content, r#"<table:table table:name="{}">"#, xml_escape(&sheet_name))?;
+                writeln!(content, r#"<table:table-column table:number-columns-repeated="{}"/>"#, columns.len().max(1))?;
+                write!(content, "<table:table-header-rows><table:table-row>")?;
+
+                for column in &columns {
+                    write!(
+                        content,
+                        r#"<table:table-cell table:style-name="header" office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+                        ods_text(&column.name),
+                    )?;
+                }
+
+                writeln!(content, "</table:table-row></table:table-header-rows>")?;
+
+                count += self.for_each_row(table, |row, n_columns| {
+                    write!(content, "<table:table-row>")?;
+
+                    for i in 0..n_columns {
+                        content.write_all(ods_cell(row.get_ref(i)?, blob).as_bytes())?;
+                    }
+
+                    writeln!(content, "</table:table-row>")?;
+                    Ok(())
+                })?;
+
+                writeln!(content, "</table:table>")?;
+                sheet_names.push(sheet_name);
+            }
+
+            writeln!(content, "</office:spreadsheet></office:body></office:document-content>")?;
+            Ok::<_, Error>(count)
+        })?;
+
+        zip.add("settings.xml", ods_settings(&sheet_names).as_bytes())?;
+        zip.add("META-INF/manifest.xml", ods_manifest().as_bytes())?;
+        zip.finish()?;
+
+        Ok(count)
+    }
+}
+
+
+/// Uses the storage class of the value, and recognises ISO 8601 dates in text
+pub fn ods_cell(value: ValueRef, blob: BlobEncoding) -> String {
+    let float = |value: String| format!(
+        r#"<table:table-cell office:value-type="float" office:value="{value}"><text:p>{value}</text:p></table:table-cell>"#
+    );
+
+    let string = |text: &str| format!(
+        r#"<table:table-cell office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+        ods_text(text)
+    );
+
+    match value {
+        ValueRef::Null => "<table:table-cell/>".to_string(),
+        ValueRef::Integer(i) if i.unsigned_abs() <= MAX_SAFE_INTEGER as u64 => float(i.to_string()),
+        ValueRef::Integer(i) => string(&i.to_string()),
+        ValueRef::Real(f) if f.is_finite() => float(format!("{f:?}")),
+        ValueRef::Real(f) => string(&f.to_string()),
+        ValueRef::Blob(b) => string(&blob.encode(b)),
+        ValueRef::Text(t) => {
+            let text = String::from_utf8_lossy(t);
+
+            match ods_date(&text) {
+                Some((value, style)) => format!(
+                    r#"<table:table-cell table:style-name="{style}" office:value-type="date" office:date-value="{value}"><text:p>{}</text:p></table:table-cell>"#,
+                    ods_text(&text)
+                ),
+                None => string(&text),
+            }
+        },
+    }
+}
+
+
+/// Returns the value as `xsd:date` or `xsd:dateTime`, with the cell style to show it in
+fn ods_date(text: &str) -> Option<(String, &'static str)> {
+    const DATE_TIME_FORMATS: [&str; 4] = [
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+
+    if let Ok(date) = NaiveDate::parse_from_str(text, "%Y-%m-%d") {
+        return Some((date.format("%Y-%m-%d").to_string(), "date"));
+    }
+
+    DATE_TIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .map(|date_time| (date_time.format("%Y-%m-%dT%H:%M:%S%.f").to_string(), "datetime"))
+}
+
+
+/// Text for inside `<text:p>`, where whitespace collapses unless marked up
+pub fn ods_text(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    let mut previous = None;
+
+    for c in s.chars() {
+        match c {
+            ' ' if previous.is_none_or(|p| p == ' ' || p == '\n') => escaped.push_str("<text:s/>"),
+            '\n' => escaped.push_str("<text:line-break/>"),
+            '\t' => escaped.push_str("<text:tab/>"),
+            '\r' => {},
+            c if (c as u32) < 0x20 => {}, // Not allowed in XML 1.0
+            c => escaped.push_str(&xml_escape(&c.to_string())),
+        }
+
+        previous = Some(c);
+    }
+
+    escaped
+}
+
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+     .replace('<', "&lt;")
+     .replace('>', "&gt;")
+     .replace('"', "&quot;")
+}
+
+
+/// Sheet names can't contain some characters, and Excel only shows 31 of them
+pub fn ods_sheet_name(table_name: &str, taken: &[String]) -> String {
+    const MAX_LEN: usize = 31;
+
+    let name = table_name
+        .chars()
+        .map(|c| if "[]*?:/\\".contains(c) { '_' } else { c })
+        .collect::<String>()
+        .trim_matches('\'')
+        .to_string();
+
+    let name = if name.is_empty() { "Sheet".to_string() } else { name };
+
+    // Shortened to make room for the number
+    let numbered = |n: u32| {
+        let suffix = if n == 1 { String::new() } else { format!(" ({n})") };
+        let name = name.chars().take(MAX_LEN - suffix.chars().count()).collect::<String>();
+
+        format!("{name}{suffix}")
+    };
+
+    (1..)
+        .map(numbered)
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or(name)
+}
+
+
+/// Freezes the header row of every sheet
+///
+/// Docs: https://wiki.documentfoundation.org/Development/ODF_Implementer_Notes/List_of_LibreOffice_ODF_Extensions
+fn ods_settings(sheet_names: &[String]) -> String {
+    let sheets = sheet_names.iter().map(|name| format!(
+        r#"<config:config-item-map-entry config:name="{}">
+<config:config-item config:name="VerticalSplitMode" config:type="short">2</config:config-item>
+<config:config-item config:name="VerticalSplitPosition" config:type="int">1</config:config-item>
+<config:config-item config:name="ActiveSplitRange" config:type="short">2</config:config-item>
+<config:config-item config:name="PositionTop" config:type="int">0</config:config-item>
+<config:config-item config:name="PositionBottom" config:type="int">1</config:config-item>
+</config:config-item-map-entry>
+"#,
+        xml_escape(name)
+    ))
+    .collect::<String>();
+
+    format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-settings {NAMESPACES}>
+<office:settings>
+<config:config-item-set config:name="ooo:view-settings">
+<config:config-item-map-indexed config:name="Views">
+<config:config-item-map-entry>
+<config:config-item config:name="ViewId" config:type="string">view1</config:config-item>
+<config:config-item-map-named config:name="Tables">
+{sheets}</config:config-item-map-named>
+</config:config-item-map-entry>
+</config:config-item-map-indexed>
+</config:config-item-set>
+</office:settings>
+</office:document-settings>
+"#)
+}
+
+
+fn ods_manifest() -> String {
+    format!(r#"<?xml version="1.0" encoding="UTF-8"?>
+<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.3">
+<manifest:file-entry manifest:full-path="/" manifest:version="1.3" manifest:media-type="{MIME_TYPE}"/>
+<manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/>
+<manifest:file-entry manifest:full-path="settings.xml" manifest:media-type="text/xml"/>
+</manifest:manifest>
+"#)
+}
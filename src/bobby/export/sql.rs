@@ -0,0 +1,229 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io::Write;
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::Error;
+use crate::bobby::sqlite::database::Database;
+use crate::bobby::sqlite::table::Table;
+
+use super::encoding::hex;
+
+
+struct SchemaEntry {
+    kind: String,
+    name: String,
+    table_name: String,
+    sql: String,
+
+    /// Backed by a module, like FTS5 or R*Tree
+    is_virtual: bool,
+
+    /// Holds the contents of a virtual table
+    is_shadow: bool,
+}
+
+
+/// Docs: https://sqlite.org/cli.html#converting_an_entire_database_to_a_text_file
+impl Database {
+    /// Writes the schema and contents of `table`, or of the whole
+    /// database when `None`, like the `.dump` command does
+    pub fn export_sql(&self, table: Option<&Table>, writer: &mut impl Write) -> Result<u64, Error> {
+        let entries = self.schema_entries(table)?;
+        let tables = entries.iter().filter(|e| e.kind == "table").collect::<Vec<_>>();
+        let has_virtual = tables.iter().any(|e| e.is_virtual);
+        let mut count = 0;
+
+        writeln!(writer, "PRAGMA foreign_keys=OFF;")?;
+        writeln!(writer, "BEGIN TRANSACTION;")?;
+
+        // Virtual tables are added to the schema without creating them, as
+        // creating them would also create their shadow tables, which follow
+        if has_virtual {
+            writeln!(writer, "PRAGMA writable_schema=ON;")?;
+        }
+
+        // Tables and their rows first, so that views, indexes and triggers can refer to them
+        for entry in &tables {
+            if entry.is_virtual {
+                writeln!(writer,
+                    "INSERT INTO sqlite_schema(type,name,tbl_name,rootpage,sql) VALUES('table',{},{},0,{});",
+                    sql_text(&entry.name),
+                    sql_text(&entry.table_name),
+                    sql_text(&entry.sql),
+                )?;
+
+                continue; // Rows live in its shadow tables
+            }
+
+            writeln!(writer, "{};", entry.sql)?;
+
+            let rows = self.dump_rows(&entry.name, writer)?;
+
+            if !entry.is_shadow {
+                count += rows;
+            }
+        }
+
+        if has_virtual {
+            writeln!(writer, "PRAGMA writable_schema=RESET;")?; // Reads the schema again
+        }
+
+        if table.is_none() && self.has_sqlite_sequence()? {
+            writeln!(writer, "DELETE FROM sqlite_sequence;")?;
+            self.dump_rows("sqlite_sequence", writer)?;
+        }
+
+        for kind in ["index", "trigger", "view"] {
+            for entry in entries.iter().filter(|e| e.kind == kind) {
+                writeln!(writer, "{};", entry.sql)?;
+            }
+        }
+
+        writeln!(writer, "COMMIT;")?;
+        Ok(count)
+    }
+
+
+    /// The statements that create `table` and its indexes and triggers,
+    /// or everything in the database when `None`
+    pub fn schema(&self, table: Option<&Table>) -> Result<Vec<String>, Error> {
+        Ok(self.schema_entries(table)?
+            .into_iter()
+            .map(|entry| format!("{};", entry.sql))
+            .collect())
+    }
+
+
+    /// Everything about `table`, including the shadow tables of a virtual
+    /// table, or everything in the database when `None`
+    fn schema_entries(&self, table: Option<&Table>) -> Result<Vec<SchemaEntry>, Error> {
+        let connection = self.connection.borrow();
+
+        // Indexes without SQL are created by constraints in CREATE TABLE
+        // Docs: https://sqlite.org/pragma.html#pragma_table_list
+        let mut sql = connection.prepare(
+            "SELECT s.type, s.name, s.tbl_name, s.sql,
+                    IFNULL(t.type = 'virtual', 0),
+                    IFNULL(t.type = 'shadow', 0)
+             FROM sqlite_schema AS s
+             LEFT JOIN pragma_table_list AS t
+               ON t.schema = 'main' AND t.name = s.name
+             WHERE s.sql IS NOT NULL
+               AND s.name NOT LIKE 'sqlite_%'
+             ORDER BY s.rowid;"
+        )?;
+
+        let entries = sql.query_map([], |row| {
+            Ok(SchemaEntry {
+                kind: row.get(0)?,
+                name: row.get(1)?,
+                table_name: row.get(2)?,
+                sql: row.get(3)?,
+                is_virtual: row.get(4)?,
+                is_shadow: row.get(5)?,
+            })
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let Some(table) = table else {
+            return Ok(entries);
+        };
+
+        // Shadow tables are named after their virtual table, like "notes_data"
+        let table_name = table.name();
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| {
+                entry.table_name == table_name || (
+                    entry.is_shadow &&
+                    entry.table_name.rsplit_once('_').is_some_and(|(owner, _)| owner == table_name)
+                )
+            })
+            .collect())
+    }
+
+
+    fn has_sqlite_sequence(&self) -> Result<bool, Error> {
+        let connection = self.connection.borrow();
+
+        Ok(connection.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE name = 'sqlite_sequence';",
+            [],
+            |row| row.get::<_, i64>(0),
+        )? > 0)
+    }
+
+
+    fn dump_rows(&self, table_name: &str, writer: &mut impl Write) -> Result<u64, Error> {
+        let connection = self.connection.borrow();
+        let identifier = sql_identifier(table_name);
+
+        // Generated columns can't be inserted into, so they're left out
+        // Docs: https://sqlite.org/pragma.html#pragma_table_xinfo
+        let columns = connection
+            .prepare("SELECT name, hidden IN (2, 3) FROM pragma_table_xinfo(?1);")?
+            .query_map([table_name], |row| Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let names = columns
+            .iter()
+            .filter(|(_, is_generated)| !is_generated)
+            .map(|(name, _)| sql_identifier(name))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let into = match columns.iter().any(|(_, is_generated)| *is_generated) {
+            true => format!("{identifier}({names})"),
+            false => identifier.clone(),
+        };
+
+        let mut sql = connection.prepare(&format!("SELECT {names} FROM {identifier};"))?;
+        let n_columns = sql.column_count();
+        let mut rows = sql.query([])?;
+        let mut count = 0;
+
+        while let Some(row) = rows.next()? {
+            let values = (0..n_columns)
+                .map(|i| row.get_ref(i).map(sql_literal))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            writeln!(writer, "INSERT INTO {into} VALUES({});", values.join(","))?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+
+/// Docs: https://sqlite.org/lang_keywords.html
+pub fn sql_identifier(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "\"\""))
+}
+
+
+fn sql_text(s: &str) -> String {
+    sql_literal(ValueRef::Text(s.as_bytes()))
+}
+
+
+/// Docs: https://sqlite.org/lang_expr.html#literal_values_constants_
+pub fn sql_literal(value: ValueRef) -> String {
+    match value {
+        ValueRef::Null       => "NULL".to_string(),
+        ValueRef::Integer(i) => i.to_string(),
+        ValueRef::Real(f) if f.is_nan() => "NULL".to_string(),
+        ValueRef::Real(f) if f.is_infinite() => if f > 0.0 { "1e999" } else { "-1e999" }.to_string(),
+        ValueRef::Real(f)    => format!("{f:?}"), // Keeps the ".0"
+        ValueRef::Text(t)    => format!("'{}'", String::from_utf8_lossy(t).replace('\'', "''")),
+        ValueRef::Blob(b)    => format!("X'{}'", hex(b)),
+    }
+}
@@ -0,0 +1,216 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io;
+use std::io::{ Seek, SeekFrom, Write };
+
+
+/// Writes a ZIP archive of uncompressed entries, which is all
+/// OpenDocument needs, and requires for its `mimetype` entry
+///
+/// Docs: https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT
+pub struct ZipWriter<W: Write + Seek> {
+    writer: W,
+    offset: u32,
+    entries: Vec<ZipEntry>,
+}
+
+
+struct ZipEntry {
+    name: String,
+    crc: u32,
+    size: u32,
+    offset: u32,
+}
+
+
+/// Streams the data of an entry, see `ZipWriter::add_with`
+pub struct ZipEntryWriter<'a, W: Write + Seek> {
+    zip: &'a mut ZipWriter<W>,
+    crc: u32,
+    size: u32,
+}
+
+
+const VERSION: u16 = 20;
+const DOS_DATE: u16 = (1 << 5) | 1; // 1980-01-01, so exports are reproducible
+
+/// Where the CRC is in a local header, followed by the sizes
+const CRC_OFFSET: u64 = 14;
+
+
+impl<W: Write + Seek> ZipWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer, offset: 0, entries: Vec::new() }
+    }
+
+
+    pub fn add(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        self.add_with(name, |entry| entry.write_all(data))
+    }
+
+
+    /// Adds an entry whose data `f` writes, without holding it in memory.
+    /// The CRC and size in its header are filled in afterwards.
+    pub fn add_with<T, E: From<io::Error>>(
+        &mut self,
+        name: &str,
+        f: impl FnOnce(&mut ZipEntryWriter<W>) -> Result<T, E>,
+    ) -> Result<T, E>
+    {
+        let position = self.writer.stream_position()?;
+
+        let mut entry = ZipEntry {
+            name: name.to_string(),
+            crc: 0,
+            size: 0,
+            offset: self.offset,
+        };
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend(0x04034b50_u32.to_le_bytes());
+        header.extend(VERSION.to_le_bytes());
+        header.extend(entry.fields());
+        header.extend(0_u16.to_le_bytes()); // Extra field length
+        header.extend(name.as_bytes());
+
+        self.write(&header)?;
+
+        let mut entry_writer = ZipEntryWriter { zip: self, crc: !0, size: 0 };
+        let result = f(&mut entry_writer)?;
+
+        entry.crc = !entry_writer.crc;
+        entry.size = entry_writer.size;
+
+        let mut sizes = Vec::with_capacity(12);
+        sizes.extend(entry.crc.to_le_bytes());
+        sizes.extend(entry.size.to_le_bytes()); // Compressed
+        sizes.extend(entry.size.to_le_bytes()); // Uncompressed
+
+        let end = self.writer.stream_position()?;
+        self.writer.seek(SeekFrom::Start(position + CRC_OFFSET))?;
+        self.writer.write_all(&sizes)?;
+        self.writer.seek(SeekFrom::Start(end))?;
+
+        self.entries.push(entry);
+        Ok(result)
+    }
+
+
+    /// Writes the central directory and returns the inner writer
+    pub fn finish(mut self) -> io::Result<W> {
+        let start = self.offset;
+        let mut directory = Vec::new();
+
+        for entry in &self.entries {
+            directory.extend(0x02014b50_u32.to_le_bytes());
+            directory.extend(VERSION.to_le_bytes()); // Made by
+            directory.extend(VERSION.to_le_bytes()); // Needed to extract
+            directory.extend(entry.fields());
+            directory.extend([0; 12]); // Extra, comment, disk, attributes
+            directory.extend(entry.offset.to_le_bytes());
+            directory.extend(entry.name.as_bytes());
+        }
+
+        let count = u16::try_from(self.entries.len()).unwrap_or(u16::MAX);
+
+        let mut end = Vec::with_capacity(22);
+        end.extend(0x06054b50_u32.to_le_bytes());
+        end.extend([0; 4]); // Disk numbers
+        end.extend(count.to_le_bytes());
+        end.extend(count.to_le_bytes());
+        end.extend((directory.len() as u32).to_le_bytes());
+        end.extend(start.to_le_bytes());
+        end.extend(0_u16.to_le_bytes()); // Comment length
+
+        self.write(&directory)?;
+        self.write(&end)?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+
+
+    fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.offset = u32::try_from(self.offset as usize + bytes.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::FileTooLarge, "Archive is over 4 GB"))?;
+
+        self.writer.write_all(bytes)
+    }
+}
+
+
+impl<W: Write + Seek> Write for ZipEntryWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.size = u32::try_from(self.size as usize + buf.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::FileTooLarge, "Entry is over 4 GB"))?;
+
+        self.zip.write(buf)?;
+        self.crc = crc32_update(self.crc, buf);
+
+        Ok(buf.len())
+    }
+
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.zip.writer.flush()
+    }
+}
+
+
+impl ZipEntry {
+    /// The fields shared by the local and central headers, from flags up to the name length
+    fn fields(&self) -> Vec<u8> {
+        let mut fields = Vec::with_capacity(24);
+
+        fields.extend(0_u16.to_le_bytes()); // Flags
+        fields.extend(0_u16.to_le_bytes()); // Stored
+        fields.extend(0_u16.to_le_bytes()); // Time
+        fields.extend(DOS_DATE.to_le_bytes());
+        fields.extend(self.crc.to_le_bytes());
+        fields.extend(self.size.to_le_bytes()); // Compressed
+        fields.extend(self.size.to_le_bytes()); // Uncompressed
+        fields.extend((self.name.len() as u16).to_le_bytes());
+
+        fields
+    }
+}
+
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0_u32; 256];
+    let mut i = 0;
+
+    while i < 256 {
+        let mut c = i as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            c = if c & 1 == 1 { 0xEDB88320 ^ (c >> 1) } else { c >> 1 };
+            bit += 1;
+        }
+
+        table[i] = c;
+        i += 1;
+    }
+
+    table
+}
+
+
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !crc32_update(!0, bytes)
+}
+
+
+fn crc32_update(crc: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(crc, |crc, byte| {
+        CRC_TABLE[((crc ^ *byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
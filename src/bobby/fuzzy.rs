@@ -0,0 +1,87 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+const MATCH: i64 = 1;
+const CONSECUTIVE: i64 = 10;
+const WORD_START: i64 = 8;
+
+
+/// How well `query` matches `candidate`, higher being better, or `None` when
+/// not all of its characters appear in order. Runs of characters and matches
+/// at the start of words count more, so "oi" prefers "order_items" to "notice".
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(lowercase)
+        .collect::<Vec<_>>();
+
+    let chars = candidate.chars().collect::<Vec<_>>();
+    let lower = chars.iter().copied().map(lowercase).collect::<Vec<_>>();
+
+    let Some(first) = query.first() else {
+        return Some(0);
+    };
+
+    // Try every place the query could start, as the first one isn't always the best
+    let best = lower
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| *c == first)
+        .filter_map(|(start, _)| score_from(&query, &chars, &lower, start))
+        .max()?;
+
+    // Prefer shorter candidates when the matches are equally good
+    Some(best * 8 - (chars.len() - query.len()) as i64)
+}
+
+
+fn score_from(query: &[char], chars: &[char], lower: &[char], start: usize) -> Option<i64> {
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for (i, c) in lower.iter().enumerate().skip(start) {
+        if next == query.len() {
+            break;
+        }
+
+        if *c != query[next] {
+            continue;
+        }
+
+        score += MATCH;
+
+        if previous.is_some_and(|p| p + 1 == i) {
+            score += CONSECUTIVE;
+        }
+
+        if is_word_start(chars, i) {
+            score += WORD_START;
+        }
+
+        previous = Some(i);
+        next += 1;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+
+/// After a separator like "_", "-", "." or a space, or a camelCase hump
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    let Some(before) = i.checked_sub(1).map(|i| chars[i]) else {
+        return true;
+    };
+
+    !before.is_alphanumeric() || (before.is_lowercase() && chars[i].is_uppercase())
+}
+
+
+fn lowercase(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
@@ -0,0 +1,70 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+const MAX_ENTRIES: usize = 100;
+
+
+/// Places visited before and after the current one, like in a web browser.
+/// The current place isn't kept, as it's read fresh when leaving it.
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    back: Vec<T>,
+    forward: Vec<T>,
+}
+
+
+// Derived, it'd need T to be Default too
+impl<T> Default for History<T> {
+    fn default() -> Self {
+        Self { back: Vec::new(), forward: Vec::new() }
+    }
+}
+
+
+impl<T: PartialEq> History<T> {
+    /// Remembers `from` when going somewhere new, which drops the way forward
+    pub fn visit(&mut self, from: T) {
+        self.forward.clear();
+
+        if self.back.last() == Some(&from) {
+            return;
+        }
+
+        self.back.push(from);
+
+        if self.back.len() > MAX_ENTRIES {
+            self.back.remove(0);
+        }
+    }
+
+
+    /// The place to go back to, remembering `from` to come forward again
+    pub fn back(&mut self, from: T) -> Option<T> {
+        let to = self.back.pop()?;
+        self.forward.push(from);
+
+        Some(to)
+    }
+
+
+    /// The place to go forward to, remembering `from` to go back again
+    pub fn forward(&mut self, from: T) -> Option<T> {
+        let to = self.forward.pop()?;
+        self.back.push(from);
+
+        Some(to)
+    }
+
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+}
@@ -0,0 +1,191 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::PathBuf;
+use std::str;
+
+
+/// A file, and optionally a table, row and column in it. Written as
+/// "file.db#orders:1234" on the command line, or as a link like
+/// "bobby:///path/to/file.db?table=orders&rowid=1234&column=status"
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+    pub path: PathBuf,
+    pub table_name: Option<String>,
+    pub rowid: Option<i64>,
+    pub column: Option<String>,
+}
+
+
+pub const URI_SCHEME: &str = "bobby";
+
+
+impl Location {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            ..Default::default()
+        }
+    }
+
+
+    /// Reads "table" or "table:rowid"
+    pub fn with_fragment(mut self, fragment: &str) -> Self {
+        if fragment.is_empty() {
+            return self;
+        }
+
+        // Table names may contain colons, rowids don't
+        let split = fragment
+            .rsplit_once(':')
+            .and_then(|(table_name, rowid)| Some((table_name, rowid.parse::<i64>().ok()?)));
+
+        match split {
+            Some((table_name, rowid)) => {
+                self.table_name = Some(table_name.to_string()).filter(|name| !name.is_empty());
+                self.rowid = Some(rowid);
+            },
+            None => self.table_name = Some(fragment.to_string()),
+        }
+
+        self
+    }
+
+
+    /// Reads "table=orders&rowid=1234&column=status", ignoring unknown keys
+    pub fn with_query(mut self, query: &str) -> Self {
+        for pair in query.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = percent_decode(&value.replace('+', " "));
+
+            match key {
+                "table"  => self.table_name = Some(value).filter(|v| !v.is_empty()),
+                "rowid"  => self.rowid = value.parse().ok(),
+                "column" => self.column = Some(value).filter(|v| !v.is_empty()),
+                _ => {},
+            }
+        }
+
+        self
+    }
+
+
+    pub fn query(&self) -> String {
+        let mut pairs = Vec::new();
+
+        if let Some(table_name) = &self.table_name {
+            pairs.push(format!("table={}", percent_encode(table_name, false)));
+        }
+
+        if let Some(rowid) = self.rowid {
+            pairs.push(format!("rowid={rowid}"));
+        }
+
+        if let Some(column) = &self.column {
+            pairs.push(format!("column={}", percent_encode(column, false)));
+        }
+
+        pairs.join("&")
+    }
+
+
+    /// Docs: https://www.rfc-editor.org/rfc/rfc3986
+    pub fn from_uri(uri: &str) -> Result<Self, String> {
+        let rest = uri
+            .strip_prefix(&format!("{URI_SCHEME}://"))
+            .ok_or(format!("Not a {URI_SCHEME}:// link: {uri}"))?;
+
+        let rest = rest.split_once('#').map_or(rest, |(rest, _)| rest);
+        let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+
+        // Only files on this computer
+        let path = match rest.split_once('/') {
+            Some(("" | "localhost", path)) => format!("/{path}"),
+            Some((host, _)) => return Err(format!("Can't open files on {host}")),
+            None => return Err(format!("Missing file in {uri}")),
+        };
+
+        Ok(Location::new(percent_decode(&path)).with_query(query))
+    }
+
+
+    pub fn to_uri(&self) -> String {
+        let path = percent_encode(&self.path.to_string_lossy(), true);
+        let query = self.query();
+
+        match query.is_empty() {
+            true => format!("{URI_SCHEME}://{path}"),
+            false => format!("{URI_SCHEME}://{path}?{query}"),
+        }
+    }
+
+
+    pub fn has_target(&self) -> bool {
+        self.table_name.is_some() || self.rowid.is_some() || self.column.is_some()
+    }
+}
+
+
+impl str::FromStr for Location {
+    type Err = String;
+
+    /// Everything after the last '#' is the fragment, so check whether
+    /// the whole string is an existing path first
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with(&format!("{URI_SCHEME}:")) {
+            return Location::from_uri(s);
+        }
+
+        match s.rsplit_once('#') {
+            Some(("", _)) => Err(format!("Missing file in {s}")),
+            Some((path, fragment)) => Ok(Location::new(path).with_fragment(fragment)),
+            None => Ok(Location::new(s)),
+        }
+    }
+}
+
+
+fn percent_encode(s: &str, keep_slashes: bool) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            b'/' if keep_slashes => encoded.push('/'),
+            byte => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    encoded
+}
+
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            },
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            },
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).to_string()
+}
@@ -5,15 +5,41 @@
 //   the terms of the GNU General Public License v3 or any later version.
 
 
+pub mod error;
+pub use error::Error;
+
+pub mod escape;
+pub mod fuzzy;
+pub mod history;
+pub mod location;
+pub mod recent;
+pub mod session;
+pub mod state;
+pub mod view;
+
 pub mod sqlite {
     pub mod affinity;
     pub mod cache;
     pub mod column;
     pub mod database;
+    pub mod fingerprint;
+    pub mod jump;
+    pub mod range;
     pub mod row;
     pub mod table;
 }
 
+pub mod export {
+    pub mod copy;
+    pub mod csv;
+    pub mod encoding;
+    pub mod format;
+    pub mod json;
+    pub mod ods;
+    pub mod sql;
+    pub mod zip;
+}
+
 pub mod prelude;
 
 #[cfg(test)]
@@ -5,10 +5,24 @@
 //   the terms of the GNU General Public License v3 or any later version.
 
 
+pub use crate::bobby::export::copy::CopyFormat;
+pub use crate::bobby::export::copy::ValuesFormat;
+pub use crate::bobby::export::csv::CsvOptions;
+pub use crate::bobby::export::encoding::BlobEncoding;
+pub use crate::bobby::export::encoding::NullEncoding;
+pub use crate::bobby::export::format::ExportFormat;
+pub use crate::bobby::location::Location;
+
 pub use crate::bobby::sqlite::affinity::Affinity;
 pub use crate::bobby::sqlite::column::Column;
 pub use crate::bobby::sqlite::column::ColumnSeparator;
 pub use crate::bobby::sqlite::database::Database;
+pub use crate::bobby::sqlite::database::OpenMode;
+pub use crate::bobby::sqlite::fingerprint::Changes;
+pub use crate::bobby::sqlite::fingerprint::Fingerprints;
+pub use crate::bobby::sqlite::fingerprint::RowChange;
+pub use crate::bobby::sqlite::jump::RowTarget;
+pub use crate::bobby::sqlite::range::CellRange;
 pub use crate::bobby::sqlite::row::Row;
 pub use crate::bobby::sqlite::row::RowOrder;
 pub use crate::bobby::sqlite::table::Table;
@@ -0,0 +1,140 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::{ Path, PathBuf };
+
+use crate::bobby::Error;
+use crate::bobby::escape::{ escape, unescape };
+use crate::bobby::state::{ state_read, state_write };
+
+
+const MAX_FILES: usize = 20;
+
+
+/// A database that was opened before, and where it was left
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecentFile {
+    pub path: PathBuf,
+    pub table_name: Option<String>,
+
+    /// Seconds since the Unix epoch
+    pub opened: i64,
+
+    /// In bytes, as it was when last opened
+    pub size: u64,
+}
+
+
+impl RecentFile {
+    pub fn exists(&self) -> bool {
+        self.path.is_file()
+    }
+}
+
+
+/// Recently opened databases, the most recent first
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecentFiles {
+    files: Vec<RecentFile>,
+}
+
+
+impl RecentFiles {
+    /// Reads the list kept at `path`, which is empty when there's no file yet
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        Ok(Self::parse(&state_read(path)?))
+    }
+
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        state_write(path, &self.to_string())
+    }
+
+
+    /// One file per line: "opened\tsize\ttable\tpath", skipping lines that don't read
+    pub fn parse(text: &str) -> Self {
+        let files = text
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(4, '\t');
+
+                let opened = fields.next()?.parse().ok()?;
+                let size = fields.next()?.parse().ok()?;
+                let table_name = Some(unescape(fields.next()?)).filter(|name| !name.is_empty());
+                let path = PathBuf::from(unescape(fields.next()?));
+
+                Some(RecentFile { path, table_name, opened, size })
+            })
+            .take(MAX_FILES)
+            .collect();
+
+        Self { files }
+    }
+
+
+    pub fn files(&self) -> &[RecentFile] {
+        &self.files
+    }
+
+    pub fn find(&self, path: &Path) -> Option<&RecentFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+
+    /// Moves `file` to the top, keeping the table it was left at
+    /// when it isn't given one
+    pub fn add(&mut self, mut file: RecentFile) {
+        if let Some(previous) = self.remove(&file.path) &&
+           file.table_name.is_none()
+        {
+            file.table_name = previous.table_name;
+        }
+
+        self.files.insert(0, file);
+        self.files.truncate(MAX_FILES);
+    }
+
+
+    /// Remembers the table `path` was left at, without moving it
+    pub fn set_table(&mut self, path: &Path, table_name: &str) -> bool {
+        match self.files.iter_mut().find(|file| file.path == path) {
+            Some(file) if file.table_name.as_deref() != Some(table_name) => {
+                file.table_name = Some(table_name.to_string());
+                true
+            },
+            _ => false,
+        }
+    }
+
+
+    pub fn remove(&mut self, path: &Path) -> Option<RecentFile> {
+        let index = self.files.iter().position(|file| file.path == path)?;
+        Some(self.files.remove(index))
+    }
+
+
+    /// Forgets files that have been moved or deleted
+    pub fn remove_missing(&mut self) {
+        self.files.retain(RecentFile::exists);
+    }
+}
+
+
+impl std::fmt::Display for RecentFiles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for file in &self.files {
+            writeln!(f, "{}\t{}\t{}\t{}",
+                file.opened,
+                file.size,
+                escape(file.table_name.as_deref().unwrap_or_default()),
+                escape(&file.path.to_string_lossy()),
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::fmt;
+use std::path::{ Path, PathBuf };
+
+use crate::bobby::Error;
+use crate::bobby::escape::{ escape, unescape };
+use crate::bobby::state::{ state_read, state_write };
+
+
+/// The windows that were open when Bobby was last closed
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Session {
+    pub windows: Vec<WindowSession>,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowSession {
+    pub path: PathBuf,
+    pub width: i32,
+    pub height: i32,
+    pub maximized: bool,
+
+    /// In the order they were in, with the one that was shown marked
+    pub tabs: Vec<TabSession>,
+}
+
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TabSession {
+    pub table_name: String,
+    pub is_selected: bool,
+    pub h_value: f64,
+    pub v_value: f64,
+
+    /// Selected rows as runs of (first position, number of rows)
+    pub selection: Vec<(u32, u32)>,
+}
+
+
+impl WindowSession {
+    /// The table that was shown
+    pub fn table_name(&self) -> Option<&str> {
+        self.tabs
+            .iter()
+            .find(|tab| tab.is_selected)
+            .or(self.tabs.first())
+            .map(|tab| tab.table_name.as_str())
+    }
+}
+
+
+impl Session {
+    /// Reads the session kept at `path`, which is empty when there's no file yet
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        Ok(Self::parse(&state_read(path)?))
+    }
+
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        state_write(path, &self.to_string())
+    }
+
+
+    /// A "window" line for each window, followed by a "tab" line for each of its
+    /// tabs. Lines that don't read are skipped, as are tabs without a window.
+    pub fn parse(text: &str) -> Self {
+        let mut windows: Vec<WindowSession> = Vec::new();
+
+        for line in text.lines() {
+            let mut fields = line.splitn(2, '\t');
+
+            match (fields.next(), fields.next()) {
+                (Some("window"), Some(rest)) => {
+                    if let Some(window) = parse_window(rest) {
+                        windows.push(window);
+                    }
+                },
+                (Some("tab"), Some(rest)) => {
+                    if let Some(window) = windows.last_mut() &&
+                       let Some(tab) = parse_tab(rest)
+                    {
+                        window.tabs.push(tab);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        Self { windows }
+    }
+}
+
+
+/// "width\theight\tmaximized\tpath"
+fn parse_window(text: &str) -> Option<WindowSession> {
+    let mut fields = text.splitn(4, '\t');
+
+    Some(WindowSession {
+        width: fields.next()?.parse().ok()?,
+        height: fields.next()?.parse().ok()?,
+        maximized: fields.next()? == "1",
+        path: PathBuf::from(unescape(fields.next()?)),
+        tabs: Vec::new(),
+    })
+}
+
+
+/// "selected\th_value\tv_value\tselection\ttable"
+fn parse_tab(text: &str) -> Option<TabSession> {
+    let mut fields = text.splitn(5, '\t');
+
+    let is_selected = fields.next()? == "1";
+    let h_value = fields.next()?.parse().ok()?;
+    let v_value = fields.next()?.parse().ok()?;
+
+    let selection = fields.next()?
+        .split(',')
+        .filter(|run| !run.is_empty())
+        .map(|run| {
+            let (first, n) = run.split_once('+')?;
+            Some((first.parse().ok()?, n.parse().ok()?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let table_name = unescape(fields.next()?);
+
+    Some(TabSession { table_name, is_selected, h_value, v_value, selection })
+}
+
+
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for window in &self.windows {
+            writeln!(f, "window\t{}\t{}\t{}\t{}",
+                window.width,
+                window.height,
+                u8::from(window.maximized),
+                escape(&window.path.to_string_lossy()),
+            )?;
+
+            for tab in &window.tabs {
+                let selection = tab.selection
+                    .iter()
+                    .map(|(first, n)| format!("{first}+{n}"))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                writeln!(f, "tab\t{}\t{}\t{}\t{selection}\t{}",
+                    u8::from(tab.is_selected),
+                    tab.h_value,
+                    tab.v_value,
+                    escape(&tab.table_name),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Turns sorted row positions into runs of (first position, number of rows)
+pub fn position_runs(positions: &[u32]) -> Vec<(u32, u32)> {
+    let mut runs: Vec<(u32, u32)> = Vec::new();
+
+    for &position in positions {
+        match runs.last_mut() {
+            Some((first, n)) if *first + *n == position => *n += 1,
+            _ => runs.push((position, 1)),
+        }
+    }
+
+    runs
+}
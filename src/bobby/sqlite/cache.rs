@@ -5,13 +5,19 @@
 //   under the terms of the GNU General Public License v3 or any later version.
 
 
+use std::cell::Ref;
 use std::rc::Rc;
 
 use gio::glib;
 use gio::glib::Object;
+use gio::prelude::ListModelExt;
 use gtk4::subclass::prelude::ObjectSubclassIsExt;
 
-use super::database::Database;
+use crate::bobby::Error;
+
+use super::database::{ Database, OpenMode };
+use super::fingerprint::Fingerprints;
+use super::row::{ Row, RowOrder };
 use super::table::Table;
 
 
@@ -29,14 +35,125 @@ impl DatabaseCacheModel {
         *imp.database.borrow_mut() = Rc::new(database.clone());
         *imp.table.borrow_mut() = Rc::new(table.clone());
 
+        if table.has_row_id() == Some(true) {
+            let max_rowid = database.max_rowid(table).ok().flatten();
+
+            imp.max_rowid.set(max_rowid);
+            *imp.fingerprints.borrow_mut() = Some(Fingerprints::new(table, max_rowid));
+        }
+
         obj
     }
+
+
+    /// What the rows loaded so far looked like
+    pub fn fingerprints(&self) -> Option<Fingerprints> {
+        self.imp().fingerprints.borrow().clone()
+    }
+
+
+    /// Marks rows that changed since `previous` was recorded
+    pub fn set_previous(&self, previous: Fingerprints) {
+        let imp = self.imp();
+        let (_, table) = self.database_and_table();
+
+        if previous.table_name != table.name() || previous.is_empty() {
+            return;
+        }
+
+        *imp.previous.borrow_mut() = Some(previous);
+        imp.cached_rows.borrow_mut().clear();
+
+        let n_items = self.n_items();
+        self.items_changed(0, n_items, n_items);
+    }
+
+
+    /// Picks up rows committed since the last call, only announcing
+    /// the new range to the view. Returns the number of new rows.
+    pub fn refresh(&self) -> Result<u32, Error> {
+        let imp = self.imp();
+
+        let (mut database, table) = self.database_and_table();
+
+        // Connections opened immutable don't see commits by others
+        if database.open_mode == OpenMode::Immutable {
+            database = Rc::new(database.live()?);
+            *imp.database.borrow_mut() = database.clone();
+        }
+
+        // Only changes on commits, and cheap to ask for every time
+        let data_version = database.data_version();
+
+        if imp.data_version.replace(data_version) == data_version {
+            return Ok(0);
+        }
+
+        let old_count = self.n_items();
+        let new_count = database.row_count(&table)?;
+
+        // New rows come after the highest rowid seen. Anything else
+        // means rows were deleted too, or the table has no rowids.
+        let added = if table.has_row_id() == Some(true) {
+            let max_rowid = database.max_rowid(&table)?;
+            database.count_after(&table, imp.max_rowid.replace(max_rowid))?
+        } else {
+            new_count.saturating_sub(old_count)
+        };
+
+        imp.row_count.set(Some(new_count));
+        imp.cached_rows.borrow_mut().clear();
+
+        if table.has_row_id() != Some(true) || old_count + added != new_count {
+            // Start over
+            self.items_changed(0, old_count, new_count);
+        } else if self.is_newest_first() {
+            self.items_changed(0, 0, added);
+        } else {
+            self.items_changed(old_count, 0, added);
+        }
+
+        Ok(added)
+    }
+
+
+    pub fn database_and_table(&self) -> (Rc<Database>, Rc<Table>) {
+        let imp = self.imp();
+
+        let database = Rc::clone(&imp.database.borrow());
+        let table = Rc::clone(&imp.table.borrow());
+
+        (database, table)
+    }
+
+
+    /// The text of the cells in `column` of the rows loaded so far
+    pub fn cached_texts(&self, column: usize) -> Vec<String> {
+        self.imp()
+            .cached_rows
+            .borrow()
+            .values()
+            .filter_map(|boxed| {
+                let row: Ref<Row> = boxed.borrow();
+                row.cells.get(column).map(|cell| cell.to_string())
+            })
+            .collect()
+    }
+
+
+    /// Whether new rows show up at the top
+    pub fn is_newest_first(&self) -> bool {
+        let (database, table) = self.database_and_table();
+
+        table.has_row_id() == Some(true) &&
+            matches!(database.row_order.unwrap_or_default(), RowOrder::Descending)
+    }
 }
 
 
 mod imp {
     use std::cell::Cell;
-    use std::cell::RefCell;
+    use std::cell::{ Ref, RefCell };
     use std::collections::BTreeMap;
     use std::rc::Rc;
 
@@ -48,6 +165,8 @@ mod imp {
     use gio::glib::BoxedAnyObject;
 
     use super::super::database::Database;
+    use super::super::fingerprint::Fingerprints;
+    use super::super::row::Row;
     use super::super::table::Table;
 
 
@@ -60,8 +179,15 @@ mod imp {
         pub database: RefCell<Rc<Database>>,
         pub table: RefCell<Rc<Table>>,
 
-        row_count: Cell<Option<u32>>,
-        cached_rows: RefCell<BTreeMap<u32, BoxedAnyObject>>,
+        pub row_count: Cell<Option<u32>>,
+        pub cached_rows: RefCell<BTreeMap<u32, BoxedAnyObject>>,
+
+        pub fingerprints: RefCell<Option<Fingerprints>>,
+        pub previous: RefCell<Option<Fingerprints>>,
+
+        /// As of the last refresh
+        pub data_version: Cell<Option<i64>>,
+        pub max_rowid: Cell<Option<i64>>,
     }
 
 
@@ -77,6 +203,17 @@ mod imp {
 
     impl ObjectImpl for DatabaseCacheModelImpl {}
 
+
+    impl DatabaseCacheModelImpl {
+        fn cached_rowid(&self, index: u32) -> Option<i64> {
+            let cached_rows = self.cached_rows.borrow();
+            let row: Ref<Row> = cached_rows.get(&index)?.borrow();
+
+            row.rowid
+        }
+    }
+
+
     impl ListModelImpl for DatabaseCacheModelImpl {
         fn item(&self, index: u32) -> Option<glib::Object> {
             // println!("item({index})");
@@ -87,13 +224,27 @@ mod imp {
                 let database = self.database.borrow();
                 let table = self.table.borrow();
 
-                let offset: u32 = index.saturating_sub(CACHE_PAGE_SIZE / 2);
+                // While scrolling, seek from the row next to it
+                let (offset, mut rows) =
+                    if let Some(rowid) = index.checked_sub(1).and_then(|i| self.cached_rowid(i)) {
+                        (index, database.rows_next_to(&table, rowid, true, CACHE_PAGE_SIZE).ok()?)
+                    } else if let Some(rowid) = self.cached_rowid(index + 1) {
+                        let rows = database.rows_next_to(&table, rowid, false, CACHE_PAGE_SIZE).ok()?;
+                        ((index + 1).saturating_sub(rows.len() as u32), rows)
+                    } else {
+                        let offset = index.saturating_sub(CACHE_PAGE_SIZE / 2);
+                        (offset, database.rows(&table, Some(offset), Some(CACHE_PAGE_SIZE)).ok()?)
+                    };
+
+                if let Some(previous) = self.previous.borrow().as_ref() {
+                    for row in rows.iter_mut() {
+                        row.change = previous.compare(row);
+                    }
+                }
 
-                let rows = database.rows(
-                    &table,
-                    Some(offset),
-                    Some(CACHE_PAGE_SIZE),
-                ).ok()?;
+                if let Some(fingerprints) = self.fingerprints.borrow_mut().as_mut() {
+                    fingerprints.record(&rows);
+                }
 
                 {
                     let mut cached_rows = self.cached_rows.borrow_mut();
@@ -5,9 +5,10 @@
 //   the terms of the GNU General Public License v3 or any later version.
 
 
-use std::error::Error;
 use std::str;
 
+use crate::bobby::Error;
+
 use super::affinity::Affinity;
 use super::database::Database;
 use super::table::Table;
@@ -19,21 +20,30 @@ pub struct Column {
     pub name: String,
     pub affinity: Affinity,
     pub primary_key: bool,
+    /// Place in the primary key counting from 1, or 0 when not part of it
+    pub primary_key_index: u32,
     pub not_null: bool,
     pub default: Option<String>,
     pub unique: bool,
+    /// Computed from other columns, so it can't be written to
+    pub generated: bool,
 }
 
 
 impl Database {
-    pub fn columns(&self, table: &Table) -> Result<Vec<Column>, Box<dyn Error>> {
+    /// The columns `SELECT *` returns, in the same order. Unlike `table_info`,
+    /// that includes generated columns, but not the hidden ones of virtual tables.
+    pub fn columns(&self, table: &Table) -> Result<Vec<Column>, Error> {
         let connection = self.connection.borrow();
 
+        // Docs: https://sqlite.org/pragma.html#pragma_table_xinfo
         let mut sql = connection.prepare(
-            &format!("PRAGMA table_info({});", table.name())
+            "SELECT cid, name, type, \"notnull\", dflt_value, pk, hidden IN (2, 3)
+             FROM pragma_table_xinfo(?1)
+             WHERE hidden != 1;"
         )?;
 
-        let iter = sql.query_map([], |row| {
+        let iter = sql.query_map([table.name()], |row| {
             let cell2: String = row.get(2)?;
 
             let affinity = cell2
@@ -41,6 +51,7 @@ impl Database {
                 .unwrap_or_default();
 
             let unique = false; // TODO
+            let primary_key_index: u32 = row.get(5)?;
 
             Ok(Column {
                 id:          row.get(0)?,
@@ -48,8 +59,10 @@ impl Database {
                 affinity,
                 not_null:    row.get(3)?,
                 default:     row.get(4)?,
-                primary_key: row.get(5)?,
-                unique
+                primary_key: primary_key_index > 0,
+                primary_key_index,
+                unique,
+                generated:   row.get(6)?,
             })
         })?;
 
@@ -58,7 +71,7 @@ impl Database {
 }
 
 
-#[derive(Debug, Default)]
+#[derive(Clone, Copy, Debug, Default)]
 pub enum ColumnSeparator {
     #[default]
     Tabs,
@@ -6,10 +6,13 @@
 
 
 use std::cell::RefCell;
-use std::error::Error;
-use std::path::Path;
+use std::fmt;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::process;
 use std::rc::Rc;
-use std::time::Duration;
+use std::sync::atomic::{ AtomicU32, Ordering };
+use std::time::{ Duration, SystemTime };
 
 use gio::prelude::FileExt;
 use gio::File;
@@ -19,6 +22,9 @@ use rusqlite::{
     OpenFlags,
 };
 
+use crate::bobby::Error;
+
+use super::fingerprint::fnv1a;
 use super::row::RowOrder;
 
 
@@ -27,42 +33,243 @@ pub struct Database {
     pub file: File,
     pub connection: Rc<RefCell<Connection>>,
     pub row_order: Option<RowOrder>,
+    pub open_mode: OpenMode,
+    pub stamp: FileStamp,
+
+    /// Shared by clones, and dropped after the connection
+    snapshot: Option<Rc<Snapshot>>,
+}
+
+
+/// A copy of the file that's read instead of the file itself,
+/// removed along with the last Database reading it
+#[derive(Debug)]
+struct Snapshot {
+    path: PathBuf,
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        for path in Database::sidecar_paths(&self.path) {
+            _ = fs::remove_file(path);
+        }
+    }
+}
+
+
+/// Where snapshots go, within the directory given to `from_file`
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Tells snapshots of the same file apart, while more than one is open
+static SNAPSHOT_COUNT: AtomicU32 = AtomicU32::new(0);
+
+
+/// Changes whenever something writes to the file or its WAL file
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileStamp {
+    data_version: Option<i64>,
+    files: Vec<Option<(SystemTime, u64)>>,
+}
+
+
+/// How the file was opened, so that it can be shown to the user
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OpenMode {
+    /// No pending WAL content, so the file is read as if it can't change
+    #[default]
+    Immutable,
+
+    /// WAL database read in place, alongside the program writing to it
+    ReadOnly,
+
+    /// WAL database copied to the cache first, as reading
+    /// it in place would create files next to it
+    Snapshot,
+}
+
+impl fmt::Display for OpenMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Immutable => "Opened read-only",
+            Self::ReadOnly  => "Opened read-only, including uncheckpointed WAL changes",
+            Self::Snapshot  => "Opened a snapshot copy, as the WAL file can’t be read in place",
+        };
+
+        write!(f, "{}", s)
+    }
 }
 
 
 /// Database files to test on can be found at:
 /// http://2016.padjo.org/tutorials/sqlite-data-starterpacks
 impl Database {
-    pub fn from_file(file: &File, row_order: Option<RowOrder>) -> Result<Self, Box<dyn Error>> {
-        let uri = format!("{}?{}", file.uri(),
-            "immutable=1", // Docs: https://sqlite.org/uri.html#uriimmutable
-        );
+    /// Opens `file` for reading, copying it into `snapshots`
+    /// first when it can't be read in place
+    pub fn from_file(file: &File, row_order: Option<RowOrder>, snapshots: &Path) -> Result<Self, Error> {
+        let path = file.path().ok_or(Error::MissingPath)?;
+
+        fs::metadata(&path)
+            .and_then(|_| fs::File::open(&path))
+            .map_err(|e| Error::from_metadata(path.clone(), e))?;
+
+        // Docs: https://sqlite.org/wal.html#read_only_databases
+        let (connection, open_mode, snapshot) = match Database::sidecars(&path) {
+            (false, _) => (Database::connect_checked(file, OpenMode::Immutable)?, OpenMode::Immutable, None),
+            (true, true) => match Database::connect_checked(file, OpenMode::ReadOnly) {
+                Ok(connection) => (connection, OpenMode::ReadOnly, None),
+                Err(_) => Database::connect_snapshot(file, snapshots)?,
+            },
+            (true, false) => Database::connect_snapshot(file, snapshots)?,
+        };
+
+        Database::configure(&connection, path)?;
+
+        let mut database = Database {
+            file: file.to_owned(),
+            connection: Rc::new(RefCell::new(connection)),
+            row_order,
+            open_mode,
+            stamp: FileStamp::default(),
+            snapshot,
+        };
+
+        database.stamp = database.stamp();
+        Ok(database)
+    }
+
+
+    /// Connects again if the file was opened immutable, so that commits
+    /// by others show up in `data_version`. Snapshots never change.
+    pub fn live(&self) -> Result<Self, Error> {
+        if self.open_mode != OpenMode::Immutable {
+            return Ok(self.clone());
+        }
+
+        let path = self.file.path().ok_or(Error::MissingPath)?;
+        let connection = Database::connect_checked(&self.file, OpenMode::ReadOnly)?;
+
+        Database::configure(&connection, path)?;
+
+        Ok(Database {
+            connection: Rc::new(RefCell::new(connection)),
+            open_mode: OpenMode::ReadOnly,
+            ..self.clone()
+        })
+    }
+
+
+    fn configure(connection: &Connection, path: PathBuf) -> Result<(), Error> {
+        connection.busy_timeout(Duration::from_secs(3))
+            .and_then(|_| connection.pragma_update(None, "query_only", true))
+            .and_then(|_| connection.pragma_update(None, "foreign_keys", true))
+            .map_err(|e| Error::from_open(path, e))
+    }
+
+
+    /// Whether there's WAL content to read, and whether the
+    /// shared memory file it needs already exists
+    fn sidecars(path: &Path) -> (bool, bool) {
+        let [_, wal, shm] = Database::sidecar_paths(path);
+
+        let has_wal = fs::metadata(wal).is_ok_and(|m| m.len() > 0);
+        let has_shm = fs::metadata(shm).is_ok();
+
+        (has_wal, has_shm)
+    }
+
 
-        // TODO: Detect encryption here and return Err
+    fn connect(file: &File, open_mode: OpenMode) -> Result<Connection, Error> {
+        let path = file.path().ok_or(Error::MissingPath)?;
 
-        let connection = Connection::open_with_flags(
-            uri,
+        // Docs: https://sqlite.org/uri.html#uriimmutable
+        let parameters = match open_mode {
+            OpenMode::Immutable => "immutable=1",
+            _ => "mode=ro",
+        };
+
+        Connection::open_with_flags(
+            format!("{}?{}", file.uri(), parameters),
             OpenFlags::SQLITE_OPEN_READ_ONLY |
             OpenFlags::SQLITE_OPEN_URI
-        ).map_err(|_|
-            Box::<dyn Error>::from("Could not open a database connection")
-        )?;
+        ).map_err(|e| Error::from_open(path, e))
+    }
+
+
+    /// Connects and reads the header
+    fn connect_checked(file: &File, open_mode: OpenMode) -> Result<Connection, Error> {
+        let path = file.path().ok_or(Error::MissingPath)?;
+        let connection = Database::connect(file, open_mode)?;
+
+        // Encrypted files fail here too, as the header can't be read
+        Database::journal_mode(&connection)
+            .map_err(|e| Error::from_open(path, e))?;
+
+        Ok(connection)
+    }
+
+
+    fn connect_snapshot(
+        file: &File,
+        dir: &Path,
+    ) -> Result<(Connection, OpenMode, Option<Rc<Snapshot>>), Error>
+    {
+        let path = file.path().ok_or(Error::MissingPath)?;
+
+        let name = format!("{}-{}-{}",
+            process::id(),
+            SNAPSHOT_COUNT.fetch_add(1, Ordering::Relaxed),
+            Database::copy_name(&path).to_string_lossy(),
+        );
+
+        let copy = Database::copy_as(file, &dir.join(name))?;
+        let snapshot = Snapshot { path: copy.path().unwrap_or_default() };
+
+        let connection = Database::connect_checked(&copy, OpenMode::ReadOnly)
+            .map_err(|e| match e {
+                // Report the original file, not our copy
+                Error::Open(_, e) => Error::Open(path, e),
+                e => e,
+            })?;
 
-        if Database::journal_mode(&connection).is_none() {
-            return Err("File is not a <b>SQLite database</b> or encrypted".into());
+        Ok((connection, OpenMode::Snapshot, Some(Rc::new(snapshot))))
+    }
+
+
+    pub fn stamp(&self) -> FileStamp {
+        let files = Database::sidecar_paths(&self.file.path().unwrap_or_default())
+            .iter()
+            .map(|path| fs::metadata(path).ok())
+            .map(|metadata| metadata.and_then(|m| Some((m.modified().ok()?, m.len()))))
+            .collect();
+
+        FileStamp {
+            data_version: self.data_version(),
+            files,
         }
+    }
 
-        connection.busy_timeout(Duration::from_secs(3))?;
-        connection.pragma_update(None, "query_only", true)?;
-        connection.pragma_update(None, "foreign_keys", true)?;
 
-        Ok(
-            Database {
-                file: file.to_owned(),
-                connection: Rc::new(RefCell::new(connection)),
-                row_order,
-            }
-        )
+    /// Whether the file has changed since it was opened
+    pub fn has_changed(&self) -> bool {
+        let stamp = self.stamp();
+
+        match self.open_mode {
+            // Only changes on commits by other connections
+            OpenMode::ReadOnly => stamp.data_version != self.stamp.data_version,
+            _ => stamp.files != self.stamp.files,
+        }
+    }
+
+
+    /// The file itself, followed by its WAL and shared memory files
+    pub fn sidecar_paths(path: &Path) -> [PathBuf; 3] {
+        let sidecar = |suffix: &str| {
+            let mut sidecar = path.to_path_buf().into_os_string();
+            sidecar.push(suffix);
+            PathBuf::from(sidecar)
+        };
+
+        [path.to_path_buf(), sidecar("-wal"), sidecar("-shm")]
     }
 
 
@@ -77,16 +284,75 @@ impl Database {
     }
 
 
-    pub fn journal_mode(connection: &Connection) -> Option<String> {
-        let mode: String = connection
-            .query_row(
-                "PRAGMA journal_mode",
-                [],
-                |row| row.get(0)
-            )
-        .ok()?;
+    pub fn journal_mode(connection: &Connection) -> Result<String, rusqlite::Error> {
+        connection.query_row(
+            "PRAGMA journal_mode",
+            [],
+            |row| row.get(0)
+        )
+    }
+
+
+    /// Runs SQLite's own consistency check, returning its findings.
+    /// Works on files that fail to open as a Database.
+    pub fn integrity_check(file: &File) -> Result<Vec<String>, Error> {
+        let connection = Database::connect(file, OpenMode::Immutable)?;
+        let path = file.path().ok_or(Error::MissingPath)?;
+
+        let mut sql = connection
+            .prepare("PRAGMA integrity_check;")
+            .map_err(|e| Error::from_open(path.clone(), e))?;
+
+        let messages = sql
+            .query_map([], |row| row.get(0))
+            .and_then(|rows| rows.collect::<Result<Vec<String>, _>>())
+            .map_err(|e| Error::from_open(path, e))?;
+
+        Ok(messages)
+    }
+
+
+    /// Copies the file and its sidecar files into `dir`, so that it
+    /// can be opened without interference from its owner. Copying
+    /// the same file again replaces the earlier copy.
+    pub fn copy_to(file: &File, dir: &Path) -> Result<File, Error> {
+        let path = file.path().ok_or(Error::MissingPath)?;
+        Database::copy_as(file, &dir.join(Database::copy_name(&path)))
+    }
+
+
+    /// The file name after a hash of the whole path, so
+    /// that files with the same name get their own copy
+    fn copy_name(path: &Path) -> PathBuf {
+        let hash = fnv1a(path.as_os_str().as_encoded_bytes());
+        let name = path.file_name().unwrap_or_default();
+
+        PathBuf::from(format!("{hash:016x}-{}", name.to_string_lossy()))
+    }
+
+
+    fn copy_as(file: &File, copy: &Path) -> Result<File, Error> {
+        let path = file.path().ok_or(Error::MissingPath)?;
+
+        if let Some(dir) = copy.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::copy(&path, copy)
+            .map_err(|e| Error::from_metadata(path.clone(), e))?;
+
+        let [_, wal, _] = Database::sidecar_paths(&path);
+        let [_, wal_copy, shm_copy] = Database::sidecar_paths(copy);
+
+        // Shared memory is rebuilt from the WAL file
+        _ = fs::remove_file(&wal_copy);
+        _ = fs::remove_file(&shm_copy);
+
+        if wal.exists() {
+            fs::copy(&wal, &wal_copy)?;
+        }
 
-        Some(mode)
+        Ok(File::for_path(copy))
     }
 }
 
@@ -102,6 +368,9 @@ impl Default for Database {
             file: File::for_path(Path::new("")),
             connection: Rc::new(RefCell::new(connection)),
             row_order: None,
+            open_mode: OpenMode::default(),
+            stamp: FileStamp::default(),
+            snapshot: None,
         }
     }
 }
@@ -113,6 +382,9 @@ impl Clone for Database {
             file: self.file.clone(),
             connection: Rc::clone(&self.connection),
             row_order: self.row_order,
+            open_mode: self.open_mode,
+            stamp: self.stamp.clone(),
+            snapshot: self.snapshot.clone(),
         }
     }
 }
@@ -0,0 +1,177 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::collections::HashMap;
+use std::hash::{ Hash, Hasher };
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::Error;
+
+use super::database::Database;
+use super::row::Row;
+use super::table::Table;
+
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowChange {
+    Inserted,
+    Modified,
+}
+
+
+/// What rows looked like when they were last loaded, so that
+/// changes can be pointed out after reloading
+#[derive(Clone, Debug, Default)]
+pub struct Fingerprints {
+    pub table_name: String,
+    pub max_rowid: Option<i64>,
+
+    rows: HashMap<i64, u64>,
+    ranges: Vec<(i64, i64)>,
+}
+
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Changes {
+    pub inserted: u32,
+    pub modified: u32,
+    pub deleted: u32,
+}
+
+
+/// Hashes the whole value, as cells only keep a preview of blobs
+pub fn hash_value(value: ValueRef, hasher: &mut impl Hasher) {
+    match value {
+        ValueRef::Null       => 0_u8.hash(hasher),
+        ValueRef::Integer(i) => (1_u8, i).hash(hasher),
+        ValueRef::Real(f)    => (2_u8, f.to_bits()).hash(hasher),
+        ValueRef::Text(t)    => (3_u8, t).hash(hasher),
+        ValueRef::Blob(b)    => (4_u8, b).hash(hasher),
+    }
+}
+
+
+impl Fingerprints {
+    pub fn new(table: &Table, max_rowid: Option<i64>) -> Self {
+        Self {
+            table_name: table.name(),
+            max_rowid,
+            ..Default::default()
+        }
+    }
+
+
+    /// Remembers a page of rows, which are ordered by rowid
+    pub fn record(&mut self, rows: &[Row]) {
+        let rowids = rows.iter().filter_map(|row| row.rowid);
+
+        if let (Some(lo), Some(hi)) = (rowids.clone().min(), rowids.max()) {
+            self.ranges.push((lo, hi));
+        }
+
+        for row in rows {
+            if let Some(rowid) = row.rowid {
+                self.rows.insert(rowid, row.fingerprint);
+            }
+        }
+    }
+
+
+    pub fn compare(&self, row: &Row) -> Option<RowChange> {
+        let rowid = row.rowid?;
+
+        match self.rows.get(&rowid) {
+            Some(fingerprint) if *fingerprint != row.fingerprint => Some(RowChange::Modified),
+            Some(_) => None,
+
+            // Beyond what existed, or a gap in a page we've seen
+            None if self.max_rowid.is_none_or(|max| rowid > max) => Some(RowChange::Inserted),
+            None if self.ranges.iter().any(|(lo, hi)| (*lo..=*hi).contains(&rowid)) => Some(RowChange::Inserted),
+            None => None,
+        }
+    }
+
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+
+/// FNV-1a, a hash that unlike DefaultHasher is the same on every build
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+
+const CHUNK_SIZE: usize = 512;
+
+impl Database {
+    /// Changes when columns are added, dropped, renamed or retyped.
+    /// Kept between runs, so it uses `fnv1a`.
+    pub fn schema_fingerprint(&self, table: &Table) -> Result<u64, Error> {
+        let schema = self.columns(table)?
+            .iter()
+            .map(|column| format!("{}\t{:?}\t{}\n", column.name, column.affinity, column.primary_key))
+            .collect::<String>();
+
+        Ok(fnv1a(schema.as_bytes()))
+    }
+
+
+    pub fn max_rowid(&self, table: &Table) -> Result<Option<i64>, Error> {
+        let connection = self.connection.borrow();
+
+        let sql = format!("SELECT MAX(rowid) FROM {}", table.name());
+        Ok(connection.query_row(&sql, [], |row| row.get(0))?)
+    }
+
+
+    /// Counts the changes to `table` since `previous` was recorded.
+    /// Only rows that were loaded back then can be found modified or deleted.
+    pub fn changes_since(&self, table: &Table, previous: &Fingerprints) -> Result<Changes, Error> {
+        let table_name = table.name();
+        let mut changes = Changes::default();
+
+        let rowids = previous.rows.keys().collect::<Vec<_>>();
+
+        for chunk in rowids.chunks(CHUNK_SIZE) {
+            let list = chunk
+                .iter()
+                .map(|rowid| rowid.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+
+            let rows = self.query_rows(
+                &format!("SELECT rowid, * FROM {table_name} WHERE rowid IN ({list});"),
+                true,
+            )?;
+
+            changes.deleted += (chunk.len() - rows.len()) as u32;
+            changes.modified += rows
+                .iter()
+                .filter(|row| previous.compare(row) == Some(RowChange::Modified))
+                .count() as u32;
+        }
+
+        changes.inserted = self.count_after(table, previous.max_rowid)?;
+
+        Ok(changes)
+    }
+
+
+    /// Counts the rows after `rowid`, which was the highest one at some point
+    pub fn count_after(&self, table: &Table, rowid: Option<i64>) -> Result<u32, Error> {
+        let connection = self.connection.borrow();
+
+        let sql = format!("SELECT COUNT(*) FROM {} WHERE rowid > ?1", table.name());
+        Ok(connection.query_row(&sql, [rowid.unwrap_or(i64::MIN)], |row| row.get(0))?)
+    }
+}
@@ -0,0 +1,212 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::fmt;
+
+use rusqlite::params_from_iter;
+
+use crate::bobby::Error;
+use crate::bobby::export::sql::sql_identifier;
+
+use super::column::Column;
+use super::database::Database;
+use super::table::Table;
+
+
+/// A row to jump to, as typed in by someone
+#[derive(Clone, Debug, PartialEq)]
+pub enum RowTarget {
+    /// As shown in the row numbers column, counting from 1
+    Number(u32),
+    Rowid(i64),
+    /// The values of the primary key columns in primary key order,
+    /// separated by commas when there's more than one
+    Key(String),
+}
+
+
+impl RowTarget {
+    /// Reads `text` as the kind of target named by `kind`: "number", "rowid" or "key"
+    pub fn new(kind: &str, text: &str) -> Result<Self, String> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return Err("Nothing to jump to".into());
+        }
+
+        match kind {
+            "number" => text
+                .trim_start_matches('#')
+                .parse::<u32>().ok()
+                .filter(|n| *n > 0)
+                .map(Self::Number)
+                .ok_or(format!("Not a row number: {text}")),
+            "rowid" => text
+                .parse::<i64>()
+                .map(Self::Rowid)
+                .map_err(|_| format!("Not a rowid: {text}")),
+            "key" => Ok(Self::Key(text.to_string())),
+            _ => Err(format!("Unknown kind of row: {kind}")),
+        }
+    }
+}
+
+
+impl fmt::Display for RowTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number(number) => write!(f, "row {number}"),
+            Self::Rowid(rowid)   => write!(f, "rowid {rowid}"),
+            Self::Key(key)       => write!(f, "key ‘{key}’"),
+        }
+    }
+}
+
+
+/// Splits what was typed for a key of `n_columns` columns into its values
+pub fn key_values(text: &str, n_columns: usize) -> Option<Vec<String>> {
+    let values = match n_columns {
+        0 => return None,
+        1 => vec![text.to_string()],
+        _ => text.split(',').map(|value| value.trim().to_string()).collect(),
+    };
+
+    (values.len() == n_columns).then_some(values)
+}
+
+
+impl Database {
+    /// Where `target` shows up in the configured order, without reading
+    /// the rows before it. `None` when there's no such row.
+    pub fn row_target_position(&self, table: &Table, target: &RowTarget) -> Result<Option<u32>, Error> {
+        match target {
+            RowTarget::Number(number) => {
+                let count = self.row_count(table)?;
+                Ok(number.checked_sub(1).filter(|position| *position < count))
+            },
+            RowTarget::Rowid(rowid) => self.rowid_position(table, *rowid),
+            RowTarget::Key(text) => self.key_position(table, text),
+        }
+    }
+
+
+    fn key_position(&self, table: &Table, text: &str) -> Result<Option<u32>, Error> {
+        let mut columns = self.columns(table)?
+            .into_iter()
+            .filter(|column| column.primary_key)
+            .collect::<Vec<_>>();
+
+        // In the order of the PRIMARY KEY clause, not the table's
+        columns.sort_by_key(|column| column.primary_key_index);
+
+        let key = columns
+            .iter()
+            .map(|column| sql_identifier(&column.name))
+            .collect::<Vec<_>>();
+
+        let Some(values) = key_values(text, key.len()) else {
+            return Ok(None);
+        };
+
+        let table_name = sql_identifier(&table.name());
+        let placeholders = (1..=key.len()).map(|i| format!("?{i}")).collect::<Vec<_>>();
+
+        // Column affinity turns the typed text into numbers where needed
+        let condition = key
+            .iter()
+            .zip(&placeholders)
+            .map(|(name, placeholder)| format!("{name} = {placeholder}"))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+
+        match table.has_row_id() {
+            Some(true) => {
+                let rowid = {
+                    let connection = self.connection.borrow();
+                    let mut sql = connection.prepare(&format!("SELECT rowid FROM {table_name} WHERE {condition} LIMIT 1;"))?;
+                    let mut rows = sql.query(params_from_iter(&values))?;
+
+                    match rows.next()? {
+                        Some(row) => row.get::<_, i64>(0)?,
+                        None => return Ok(None),
+                    }
+                };
+
+                self.rowid_position(table, rowid)
+            },
+            // Stored, and so listed, in primary key order
+            Some(false) => {
+                let connection = self.connection.borrow();
+
+                let exists: bool = connection.query_row(
+                    &format!("SELECT EXISTS (SELECT 1 FROM {table_name} WHERE {condition});"),
+                    params_from_iter(&values),
+                    |row| row.get(0),
+                )?;
+
+                if !exists {
+                    return Ok(None);
+                }
+
+                let order = self.key_order(table, &columns)?;
+
+                // Rows that sort before the key, one column at a time
+                let before = (0..key.len())
+                    .map(|i| {
+                        let mut terms = (0..i)
+                            .map(|j| format!("{} = {} COLLATE {}", key[j], placeholders[j], order[j].1))
+                            .collect::<Vec<_>>();
+
+                        let (descending, collation) = &order[i];
+                        let operator = if *descending { ">" } else { "<" };
+
+                        terms.push(format!("{} {operator} {} COLLATE {collation}", key[i], placeholders[i]));
+                        format!("({})", terms.join(" AND "))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" OR ");
+
+                let position = connection.query_row(
+                    &format!("SELECT COUNT(*) FROM {table_name} WHERE {before};"),
+                    params_from_iter(&values),
+                    |row| row.get(0),
+                )?;
+
+                Ok(Some(position))
+            },
+            None => Ok(None), // Views have no key
+        }
+    }
+
+    /// Whether each of the key `columns` is stored in descending order,
+    /// and the collation it's compared with, from the primary key index
+    fn key_order(&self, table: &Table, columns: &[Column]) -> Result<Vec<(bool, String)>, Error> {
+        let connection = self.connection.borrow();
+
+        let mut sql = connection.prepare(
+            "SELECT x.name, x.desc, x.coll
+             FROM pragma_index_list(?1) AS l
+             JOIN pragma_index_xinfo(l.name) AS x
+             WHERE l.origin = 'pk' AND x.key;"
+        )?;
+
+        let order = sql
+            .query_map([table.name()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?, row.get::<_, String>(2)?))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(columns
+            .iter()
+            .map(|column| order
+                .iter()
+                .find(|(name, _, _)| *name == column.name)
+                .map(|(_, descending, collation)| (*descending, sql_identifier(collation)))
+                .unwrap_or((false, sql_identifier("BINARY"))))
+            .collect())
+    }
+}
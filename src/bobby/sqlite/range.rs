@@ -0,0 +1,94 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::ops::RangeInclusive;
+
+use super::column::{ Column, ColumnSeparator };
+use super::row::Row;
+
+
+/// A rectangle of cells between where the selection started and where it is now,
+/// as row positions and column positions in the order the columns are shown in
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CellRange {
+    pub anchor: (u32, usize),
+    pub cursor: (u32, usize),
+}
+
+
+impl CellRange {
+    pub fn new(row: u32, column: usize) -> Self {
+        Self {
+            anchor: (row, column),
+            cursor: (row, column),
+        }
+    }
+
+
+    /// The range a press on `cell` starts: a new one with Alt held. Without Alt
+    /// the press selects rows instead, which ends any range.
+    pub fn pressed(cell: Option<(u32, usize)>, alt: bool) -> Option<Self> {
+        cell.filter(|_| alt).map(|(row, column)| Self::new(row, column))
+    }
+
+
+    pub fn rows(&self) -> RangeInclusive<u32> {
+        self.anchor.0.min(self.cursor.0)..=self.anchor.0.max(self.cursor.0)
+    }
+
+
+    pub fn columns(&self) -> RangeInclusive<usize> {
+        self.anchor.1.min(self.cursor.1)..=self.anchor.1.max(self.cursor.1)
+    }
+
+
+    pub fn contains(&self, row: u32, column: usize) -> bool {
+        self.rows().contains(&row) && self.columns().contains(&column)
+    }
+
+
+    pub fn is_single(&self) -> bool {
+        self.anchor == self.cursor
+    }
+
+
+    /// Moves the cursor by a step, staying within `n_rows` and `n_columns`
+    pub fn extend(&mut self, rows: i64, columns: i64, n_rows: u32, n_columns: usize) {
+        let row = (self.cursor.0 as i64 + rows).clamp(0, n_rows.saturating_sub(1) as i64);
+        let column = (self.cursor.1 as i64 + columns).clamp(0, n_columns.saturating_sub(1) as i64);
+
+        self.cursor = (row as u32, column as usize);
+    }
+}
+
+
+impl Row {
+    /// Formats the cells in `columns` of each row, like `format_rows_with`.
+    /// The columns are given by index, in the order they're shown in.
+    pub fn format_cells_with(
+        rows: &[Row],
+        columns: &[Column],
+        indices: &[usize],
+        separator: ColumnSeparator,
+    ) -> String
+    {
+        let rows = rows
+            .iter()
+            .map(|row| Row {
+                cells: indices.iter().filter_map(|i| row.cells.get(*i).cloned()).collect(),
+                ..row.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let columns = indices
+            .iter()
+            .filter_map(|i| columns.get(*i).cloned())
+            .collect::<Vec<_>>();
+
+        Row::format_rows_with(&rows, &columns, separator)
+    }
+}
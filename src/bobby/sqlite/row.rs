@@ -5,32 +5,44 @@
 //   the terms of the GNU General Public License v3 or any later version.
 
 
-use std::error::Error;
 use std::fmt;
+use std::hash::{ DefaultHasher, Hasher };
 
 use rusqlite::types::ValueRef;
 
+use crate::bobby::Error;
+
 use super::affinity::Affinity;
-use super::column::ColumnSeparator;
+use super::column::{ Column, ColumnSeparator };
 use super::database::Database;
+use super::fingerprint::{ hash_value, RowChange };
 use super::table::Table;
 
 
 #[derive(Clone, Debug, Default)]
 pub struct Row {
     pub cells: Vec<Affinity>,
+    pub rowid: Option<i64>,
+    pub change: Option<RowChange>,
+
+    /// Of the full values, to tell when the row changed
+    pub fingerprint: u64,
 }
 
 
 const BLOB_PREVIEW_LEN: usize = 8;
 
 impl Database {
+    /// Rows by their position in the configured order. The rowid can't stand
+    /// in for the position, as rowids have gaps. An offset has to step over
+    /// every row before it though, so the cache model uses `rows_next_to`
+    /// while scrolling, and only falls back to this when jumping.
     pub fn rows(
         &self,
         table: &Table,
         offset: Option<u32>,
         limit:  Option<u32>,
-    ) -> Result<Vec<Row>, Box<dyn Error>>
+    ) -> Result<Vec<Row>, Error>
 {
         let limit = limit.unwrap_or(u32::MAX); // GTK models are limited to u32
         let offset = offset.unwrap_or(0);
@@ -40,11 +52,11 @@ impl Database {
         let sql =
             if table.has_row_id() == Some(true) {
                 &format!("
-                    SELECT *
+                    SELECT rowid, *
                     FROM {table_name}
-                    WHERE rowid >= {offset}
                     ORDER BY rowid {row_order}
-                    LIMIT {limit};
+                    LIMIT {limit}
+                    OFFSET {offset};
                 ")
             } else {
                 &format!("
@@ -55,16 +67,165 @@ impl Database {
                 ")
             };
 
+        self.query_rows(sql, table.has_row_id() == Some(true))
+    }
+
+
+    /// Up to `limit` rows that come after the row with `rowid` in the configured
+    /// order, or before it when `forward` isn't set. Both in the configured order.
+    pub fn rows_next_to(
+        &self,
+        table: &Table,
+        rowid: i64,
+        forward: bool,
+        limit: u32,
+    ) -> Result<Vec<Row>, Error>
+    {
+        let table_name = table.name();
+        let is_ascending = matches!(self.row_order.unwrap_or_default(), RowOrder::Ascending);
+
+        let (compare, order) = match is_ascending == forward {
+            true  => (">", RowOrder::Ascending),
+            false => ("<", RowOrder::Descending),
+        };
+
+        let mut rows = self.query_rows(
+            &format!("
+                SELECT rowid, *
+                FROM {table_name}
+                WHERE rowid {compare} {rowid}
+                ORDER BY rowid {order}
+                LIMIT {limit};
+            "),
+            true,
+        )?;
+
+        if !forward {
+            rows.reverse();
+        }
+
+        Ok(rows)
+    }
+
+
+    /// Rows at runs of (first position, number of rows), read from the file
+    /// in a query per run, rather than a page at a time through the cache
+    pub fn rows_at(&self, table: &Table, runs: &[(u32, u32)]) -> Result<Vec<Row>, Error> {
+        let mut rows = Vec::new();
+
+        for (first, n) in runs {
+            rows.extend(self.rows(table, Some(*first), Some(*n))?);
+        }
+
+        Ok(rows)
+    }
+
+
+    /// Calls `f` for every row of the table in the configured order, without
+    /// keeping them in memory. Returns the number of rows visited.
+    pub fn for_each_row(
+        &self,
+        table: &Table,
+        f: impl FnMut(&rusqlite::Row, usize) -> Result<(), Error>,
+    ) -> Result<u64, Error>
+    {
+        self.for_each_row_limit(table, None, f)
+    }
+
+
+    /// Like `for_each_row`, stopping after `limit` rows
+    pub fn for_each_row_limit(
+        &self,
+        table: &Table,
+        limit: Option<u64>,
+        mut f: impl FnMut(&rusqlite::Row, usize) -> Result<(), Error>,
+    ) -> Result<u64, Error>
+    {
+        let table_name = table.name();
+        let row_order = self.row_order.unwrap_or_default();
+
+        let limit = match limit {
+            Some(limit) => format!(" LIMIT {limit}"),
+            None => String::new(),
+        };
+
+        let sql = match table.has_row_id() {
+            Some(true) => format!("SELECT * FROM {table_name} ORDER BY rowid {row_order}{limit};"),
+            _ => format!("SELECT * FROM {table_name}{limit};"),
+        };
+
+        let connection = self.connection.borrow();
+
+        let mut sql = connection.prepare(&sql)?;
+        let n_columns = sql.column_count();
+        let mut rows = sql.query([])?;
+        let mut count = 0;
+
+        while let Some(row) = rows.next()? {
+            f(row, n_columns)?;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
+
+    /// Where the row with `rowid` shows up in the configured order, if it exists
+    pub fn rowid_position(&self, table: &Table, rowid: i64) -> Result<Option<u32>, Error> {
+        if table.has_row_id() != Some(true) {
+            return Ok(None);
+        }
+
+        let table_name = table.name();
+        let connection = self.connection.borrow();
+
+        let exists: bool = connection.query_row(
+            &format!("SELECT EXISTS (SELECT 1 FROM {table_name} WHERE rowid = ?1);"),
+            [rowid],
+            |row| row.get(0),
+        )?;
+
+        if !exists {
+            return Ok(None);
+        }
+
+        let before = match self.row_order.unwrap_or_default() {
+            RowOrder::Descending => ">",
+            RowOrder::Ascending  => "<",
+        };
+
+        let position = connection.query_row(
+            &format!("SELECT COUNT(*) FROM {table_name} WHERE rowid {before} ?1;"),
+            [rowid],
+            |row| row.get(0),
+        )?;
+
+        Ok(Some(position))
+    }
+
+
+    /// Runs a query whose first column is the rowid when `with_row_id` is set
+    pub fn query_rows(&self, sql: &str, with_row_id: bool) -> Result<Vec<Row>, Error> {
         let connection = self.connection.borrow();
 
         let mut sql = connection.prepare(sql)?;
         let n_columns = sql.column_count();
+        let first_column = if with_row_id { 1 } else { 0 };
 
         let iter = sql.query_map([], |row| {
+            let rowid: Option<i64> = match with_row_id {
+                true => Some(row.get(0)?),
+                false => None,
+            };
+
             let mut values = Vec::new();
+            let mut hasher = DefaultHasher::new();
+
+            for i in first_column..n_columns {
+                let value = row.get_ref(i)?;
+                hash_value(value, &mut hasher);
 
-            for i in 0..n_columns {
-                let value = match row.get_ref(i)? {
+                let value = match value {
                     ValueRef::Null       => Affinity::NULL,
                     ValueRef::Integer(i) => Affinity::INTEGER(Some(i)),
                     ValueRef::Real(f)    => Affinity::REAL(Some(f)),
@@ -83,11 +244,11 @@ impl Database {
                 values.push(value);
             }
 
-            Ok(values)
+            Ok((rowid, values, hasher.finish()))
         })?;
 
         Ok(iter
-            .map(|res| res.map(|cells| Row { cells }))
+            .map(|res| res.map(|(rowid, cells, fingerprint)| Row { cells, rowid, change: None, fingerprint }))
             .collect::<Result<Vec<_>, _>>()?
         )
     }
@@ -107,6 +268,23 @@ impl Row {
             ColumnSeparator::Markdown => format!("| {} |", collection.join(" | ")),
         }
     }
+
+
+    /// Formats one row per line, under a header and separator line in Markdown mode
+    pub fn format_rows_with(rows: &[Row], columns: &[Column], separator: ColumnSeparator) -> String {
+        let mut lines = Vec::with_capacity(rows.len() + 2);
+
+        if let ColumnSeparator::Markdown = separator {
+            let names = columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+            let rules = vec!["---"; columns.len()];
+
+            lines.push(format!("| {} |", names.join(" | ")));
+            lines.push(format!("| {} |", rules.join(" | ")));
+        }
+
+        lines.extend(rows.iter().map(|row| row.format_with(separator)));
+        lines.join("\n")
+    }
 }
 
 
@@ -5,10 +5,11 @@
 //   the terms of the GNU General Public License v3 or any later version.
 
 
-use std::error::Error;
 use std::fmt;
 use std::str;
 
+use crate::bobby::Error;
+
 use super::database::Database;
 
 
@@ -17,6 +18,7 @@ pub struct Table {
     name: TableName,
     has_row_id: Option<bool>,
     is_view: bool,
+    is_virtual: bool,
 }
 
 impl Table {
@@ -31,11 +33,16 @@ impl Table {
     pub fn is_view(&self) -> bool {
         self.is_view
     }
+
+    /// Backed by a module like FTS5 or R*Tree instead of by rows in the file
+    pub fn is_virtual(&self) -> bool {
+        self.is_virtual
+    }
 }
 
 
 impl Database {
-    pub fn tables(&self) -> Result<Vec<Table>, Box<dyn Error>> {
+    pub fn tables(&self) -> Result<Vec<Table>, Error> {
         let connection = self.connection.borrow();
 
         let mut sql = connection.prepare(
@@ -45,36 +52,43 @@ impl Database {
                  WHEN type = 'table' THEN 1
                  ELSE NULL
                END AS has_row_id,
-               type
+               type,
+               sql LIKE 'CREATE VIRTUAL TABLE%' AS is_virtual
              FROM sqlite_master
              WHERE type IN ('table', 'view')
                AND name NOT LIKE 'sqlite_%'
              ORDER BY name;"
         )?;
 
-        let tables = sql.query_map([],
+        let rows = sql.query_map([],
             |row| {
                 let name: String = row.get(0)?;
                 let has_row_id: Option<i64> = row.get(1)?;
                 let type_str: String = row.get(2)?;
+                let is_virtual: Option<bool> = row.get(3)?;
 
+                Ok((name, has_row_id, type_str, is_virtual.unwrap_or(false)))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.into_iter()
+            .map(|(name, has_row_id, type_str, is_virtual)| {
                 let name = name
                     .parse::<TableName>()
-                    .map_err(|_| rusqlite::Error::InvalidQuery)?;
+                    .map_err(|e| Error::UnsupportedSchema(format!("{name}: {e}")))?;
 
                 Ok(Table {
                     name,
                     has_row_id: has_row_id.map(|v| v != 0),
                     is_view: type_str == "view",
+                    is_virtual,
                 })
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(tables)
+            })
+            .collect()
     }
 
 
-    pub fn row_count(&self, table: &Table) -> Result<u32, Box<dyn Error>> {
+    pub fn row_count(&self, table: &Table) -> Result<u32, Error> {
         let connection = self.connection.borrow();
 
         let sql = format!("SELECT COUNT(*) FROM {}", table.name());
@@ -0,0 +1,117 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use rusqlite::types::Value;
+
+use crate::bobby::prelude::*;
+use crate::bobby::export::copy::{
+    row_as_insert,
+    row_as_json,
+    row_as_update,
+    row_as_where,
+    row_key,
+    values_as_in_list,
+    values_as_lines,
+};
+
+
+fn columns() -> Vec<String> {
+    vec!["id".to_string(), "name".to_string(), "note".to_string()]
+}
+
+fn values() -> Vec<Value> {
+    vec![Value::Integer(7), Value::Text("O'Brien".to_string()), Value::Null]
+}
+
+
+#[test]
+fn test_export_copy_row_key() {
+    let with_key = [("id".to_string(), true), ("name".to_string(), false)];
+    let without_key = [("id".to_string(), false), ("name".to_string(), false)];
+    let values = values();
+
+    assert_eq!(row_key(&with_key, &values, Some(3)), vec![("id".to_string(), Value::Integer(7))]);
+    assert_eq!(row_key(&without_key, &values, Some(3)), vec![("rowid".to_string(), Value::Integer(3))]);
+    assert_eq!(row_key(&without_key, &values, None).len(), 2);
+}
+
+
+#[test]
+fn test_export_copy_row_as() {
+    let key = vec![("id".to_string(), Value::Integer(7))];
+
+    assert_eq!(
+        row_as_json(&columns(), &values()),
+        r#"{"id": 7, "name": "O'Brien", "note": null}"#
+    );
+
+    assert_eq!(
+        row_as_insert("people", &columns(), &values()),
+        r#"INSERT INTO "people" ("id", "name", "note") VALUES (7, 'O''Brien', NULL);"#
+    );
+
+    assert_eq!(
+        row_as_update("people", &columns(), &values(), &key),
+        r#"UPDATE "people" SET "name" = 'O''Brien', "note" = NULL WHERE "id" = 7;"#
+    );
+
+    assert_eq!(
+        row_as_where(&vec![("a".to_string(), Value::Null), ("b".to_string(), Value::Real(1.0))]),
+        r#"WHERE "a" IS NULL AND "b" = 1.0"#
+    );
+}
+
+
+#[test]
+fn test_export_copy_values() {
+    let values = vec![
+        Value::Integer(1),
+        Value::Null,
+        Value::Text("it's".to_string()),
+    ];
+
+    assert_eq!(values_as_lines(&values), "1\n\nit's");
+    assert_eq!(values_as_in_list(&values), "IN (1, 'it''s')");
+    assert_eq!(values_as_in_list(&[]), "IN ()");
+}
+
+
+#[test]
+fn test_export_copy_rows_as_generated_column() -> Result<(), Box<dyn Error>> {
+    let mut database = Database::default();
+    database.row_order = Some(RowOrder::Ascending);
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (a, total AS (a * 2), b);
+         INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y'), (3, 'z');"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let runs = [(0, 1), (2, 1)];
+
+    assert_eq!(
+        database.copy_rows_as(&table, &runs, CopyFormat::Json)?,
+        vec![r#"{"a": 1, "total": 2, "b": "x"}"#, r#"{"a": 3, "total": 6, "b": "z"}"#]
+    );
+
+    assert_eq!(
+        database.copy_rows_as(&table, &runs, CopyFormat::Insert)?,
+        vec![
+            r#"INSERT INTO "t" ("a", "b") VALUES (1, 'x');"#,
+            r#"INSERT INTO "t" ("a", "b") VALUES (3, 'z');"#,
+        ]
+    );
+
+    assert_eq!(
+        database.copy_rows_as(&table, &runs[..1], CopyFormat::Update)?,
+        vec![r#"UPDATE "t" SET "a" = 1, "b" = 'x' WHERE "rowid" = 1;"#]
+    );
+
+    Ok(())
+}
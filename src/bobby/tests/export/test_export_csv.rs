@@ -0,0 +1,59 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use crate::bobby::prelude::*;
+use crate::bobby::export::csv::{ csv_field, CsvOptions };
+use crate::bobby::export::encoding::{ base64, hex, BlobEncoding, NullEncoding };
+
+
+#[test]
+fn test_export_csv_field() {
+    assert_eq!(csv_field("plain", ','), "plain");
+    assert_eq!(csv_field("a,b", ','), "\"a,b\"");
+    assert_eq!(csv_field("a,b", ';'), "a,b");
+    assert_eq!(csv_field("say \"hi\"", ','), "\"say \"\"hi\"\"\"");
+    assert_eq!(csv_field("two\nlines", ','), "\"two\nlines\"");
+    assert_eq!(csv_field("", ','), "");
+}
+
+
+#[test]
+fn test_export_encoding() {
+    assert_eq!(base64(b""), "");
+    assert_eq!(base64(b"f"), "Zg==");
+    assert_eq!(base64(b"fo"), "Zm8=");
+    assert_eq!(base64(b"foo"), "Zm9v");
+    assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+
+    assert_eq!(hex(&[0x00, 0xAB, 0xFF]), "00ABFF");
+
+    assert_eq!(BlobEncoding::Empty.encode(b"foo"), "");
+    assert_eq!(NullEncoding::Null.encode(), "NULL");
+    assert_eq!("hex".parse::<BlobEncoding>(), Ok(BlobEncoding::Hex));
+}
+
+
+#[test]
+fn test_export_csv_generated_column() -> Result<(), Box<dyn Error>> {
+    let mut database = Database::default();
+    database.row_order = Some(RowOrder::Ascending);
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (a, total AS (a * 2), b);
+         INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y');"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let mut csv = Vec::new();
+
+    database.export_csv(&table, &mut csv, &CsvOptions::default(), None)?;
+    assert_eq!(String::from_utf8(csv)?, "a,total,b\r\n1,2,x\r\n2,4,y\r\n");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::prelude::*;
+use crate::bobby::export::json::{ json_string, json_value };
+
+
+#[test]
+fn test_export_json_value() {
+    assert_eq!(json_value(ValueRef::Null), "null");
+    assert_eq!(json_value(ValueRef::Integer(-42)), "-42");
+    assert_eq!(json_value(ValueRef::Real(1.0)), "1.0");
+    assert_eq!(json_value(ValueRef::Real(0.25)), "0.25");
+    assert_eq!(json_value(ValueRef::Real(f64::NAN)), "null");
+    assert_eq!(json_value(ValueRef::Text(b"hi")), "\"hi\"");
+    assert_eq!(json_value(ValueRef::Blob(b"foo")), "{\"base64\": \"Zm9v\"}");
+}
+
+
+#[test]
+fn test_export_json_string() {
+    assert_eq!(json_string("plain"), "\"plain\"");
+    assert_eq!(json_string("a \"b\" \\ c"), "\"a \\\"b\\\" \\\\ c\"");
+    assert_eq!(json_string("line\nbreak\ttab"), "\"line\\nbreak\\ttab\"");
+    assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    assert_eq!(json_string("ünïcødé"), "\"ünïcødé\"");
+}
+
+
+#[test]
+fn test_export_json_limit() -> Result<(), Box<dyn Error>> {
+    let mut database = Database::default();
+    database.row_order = Some(RowOrder::Ascending);
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (a);
+         INSERT INTO t VALUES (1), (2), (3);"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let mut json = Vec::new();
+
+    assert_eq!(database.export_json(&table, &mut json, Some(2))?, 2);
+    assert_eq!(String::from_utf8(json)?, "[\n  {\"a\": 1},\n  {\"a\": 2}\n]\n");
+
+    Ok(())
+}
+
+
+#[test]
+fn test_export_json_generated_column() -> Result<(), Box<dyn Error>> {
+    let database = Database::default();
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (a, total AS (a * 2), b);
+         INSERT INTO t (a, b) VALUES (1, 'x');"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let mut json = Vec::new();
+
+    database.export_ndjson(&table, &mut json)?;
+    assert_eq!(String::from_utf8(json)?, "{\"a\": 1, \"total\": 2, \"b\": \"x\"}\n");
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::export::encoding::BlobEncoding;
+use crate::bobby::export::ods::{ ods_cell, ods_sheet_name, ods_text };
+
+
+#[test]
+fn test_export_ods_cell() {
+    let blob = BlobEncoding::Hex;
+
+    assert_eq!(ods_cell(ValueRef::Null, blob), "<table:table-cell/>");
+    assert!(ods_cell(ValueRef::Integer(42), blob).contains(r#"office:value-type="float" office:value="42""#));
+    assert!(ods_cell(ValueRef::Real(1.5), blob).contains(r#"office:value="1.5""#));
+    assert!(ods_cell(ValueRef::Integer(i64::MAX), blob).contains(r#"office:value-type="string""#));
+    assert!(ods_cell(ValueRef::Integer(i64::MIN), blob).contains(r#"office:value-type="string""#));
+    assert!(ods_cell(ValueRef::Integer(-(1 << 53)), blob).contains(r#"office:value-type="float""#));
+    assert!(ods_cell(ValueRef::Text(b"2024-02-29"), blob).contains(r#"office:date-value="2024-02-29""#));
+    assert!(ods_cell(ValueRef::Text(b"2024-02-29 13:45:00"), blob).contains(r#"office:date-value="2024-02-29T13:45:00""#));
+    assert!(ods_cell(ValueRef::Text(b"2023-02-29"), blob).contains(r#"office:value-type="string""#));
+    assert!(ods_cell(ValueRef::Blob(&[0xCA, 0xFE]), blob).contains("<text:p>CAFE</text:p>"));
+}
+
+
+#[test]
+fn test_export_ods_text() {
+    assert_eq!(ods_text("a < b & c"), "a &lt; b &amp; c");
+    assert_eq!(ods_text("one\ntwo\tthree"), "one<text:line-break/>two<text:tab/>three");
+    assert_eq!(ods_text(" a  b"), "<text:s/>a <text:s/>b");
+    assert_eq!(ods_text("bell\u{7}"), "bell");
+}
+
+
+#[test]
+fn test_export_ods_sheet_name() {
+    let taken = vec!["orders".to_string()];
+
+    assert_eq!(ods_sheet_name("customers", &taken), "customers");
+    assert_eq!(ods_sheet_name("orders", &taken), "orders (2)");
+    assert_eq!(ods_sheet_name("a/b:c", &taken), "a_b_c");
+    assert_eq!(ods_sheet_name(&"x".repeat(40), &taken).len(), 31);
+
+    // Still fits with the number added
+    let taken = vec!["x".repeat(31)];
+    assert_eq!(ods_sheet_name(&"x".repeat(40), &taken), format!("{} (2)", "x".repeat(27)));
+}
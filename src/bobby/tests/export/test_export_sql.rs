@@ -0,0 +1,80 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use rusqlite::Connection;
+use rusqlite::types::ValueRef;
+
+use crate::bobby::export::sql::{ sql_identifier, sql_literal };
+use crate::bobby::prelude::*;
+
+
+#[test]
+fn test_export_sql_literal() {
+    assert_eq!(sql_literal(ValueRef::Null), "NULL");
+    assert_eq!(sql_literal(ValueRef::Integer(7)), "7");
+    assert_eq!(sql_literal(ValueRef::Real(2.0)), "2.0");
+    assert_eq!(sql_literal(ValueRef::Real(f64::INFINITY)), "1e999");
+    assert_eq!(sql_literal(ValueRef::Text(b"it's")), "'it''s'");
+    assert_eq!(sql_literal(ValueRef::Blob(&[0xCA, 0xFE])), "X'CAFE'");
+}
+
+
+#[test]
+fn test_export_sql_identifier() {
+    assert_eq!(sql_identifier("orders"), "\"orders\"");
+    assert_eq!(sql_identifier("a \"b\""), "\"a \"\"b\"\"\"");
+}
+
+
+#[test]
+fn test_export_sql_round_trip() -> Result<(), Box<dyn Error>> {
+    let database = Database::default();
+
+    database.connection.borrow().execute_batch("
+        CREATE TABLE notes (
+            id INTEGER PRIMARY KEY,
+            body TEXT,
+            length INTEGER GENERATED ALWAYS AS (length(body)) VIRTUAL
+        );
+        CREATE VIRTUAL TABLE search USING fts5(body);
+
+        INSERT INTO notes (body) VALUES ('hello world');
+        INSERT INTO search VALUES ('hello world'), ('bobby browses sqlite');
+    ")?;
+
+    let mut dump = Vec::new();
+    database.export_sql(None, &mut dump)?;
+
+    let restored = Connection::open_in_memory()?;
+    restored.execute_batch(&String::from_utf8(dump)?)?;
+
+    let length: i64 = restored.query_row("SELECT length FROM notes;", [], |row| row.get(0))?;
+    assert_eq!(length, 11);
+
+    let found: String = restored.query_row(
+        "SELECT body FROM search WHERE search MATCH 'bobby';",
+        [],
+        |row| row.get(0),
+    )?;
+
+    assert_eq!(found, "bobby browses sqlite");
+
+    // Shadow tables come along with their virtual table
+    let table = database.tables()?.into_iter().find(|t| t.name() == "search").ok_or("No table")?;
+    let mut dump = Vec::new();
+    database.export_sql(Some(&table), &mut dump)?;
+
+    let restored = Connection::open_in_memory()?;
+    restored.execute_batch(&String::from_utf8(dump)?)?;
+
+    let count: i64 = restored.query_row("SELECT COUNT(*) FROM search;", [], |row| row.get(0))?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
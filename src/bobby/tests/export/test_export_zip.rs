@@ -0,0 +1,49 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io::{ Cursor, Write };
+
+use crate::bobby::export::zip::{ ZipWriter, crc32 };
+
+
+#[test]
+fn test_export_zip_crc32() {
+    assert_eq!(crc32(b""), 0);
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+}
+
+
+#[test]
+fn test_export_zip_writer() -> std::io::Result<()> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    zip.add("mimetype", b"text/plain")?;
+
+    // Streamed in parts, with the CRC and size filled in afterwards
+    zip.add_with("data", |entry| {
+        entry.write_all(b"1234")?;
+        entry.write_all(b"56789")
+    })?;
+
+    let bytes = zip.finish()?.into_inner();
+
+    assert_eq!(&bytes[..4], b"PK\x03\x04");
+    assert_eq!(&bytes[30..38], b"mimetype");
+    assert_eq!(&bytes[38..48], b"text/plain");
+
+    let data = &bytes[48..];
+    assert_eq!(&data[..4], b"PK\x03\x04");
+    assert_eq!(&data[14..18], &0xCBF43926_u32.to_le_bytes());
+    assert_eq!(&data[18..22], &9_u32.to_le_bytes());
+    assert_eq!(&data[34..43], b"123456789");
+
+    // End of central directory record, with one entry
+    let end = &bytes[bytes.len() - 22..];
+    assert_eq!(&end[..4], b"PK\x05\x06");
+    assert_eq!(end[10], 2);
+
+    Ok(())
+}
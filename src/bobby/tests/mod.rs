@@ -1,5 +1,27 @@
-#[cfg(test)]
+#![cfg(test)]
+
+mod test_error;
+mod test_fuzzy;
+mod test_history;
+mod test_location;
+mod test_recent;
+mod test_session;
+mod test_view;
+
+pub mod export {
+    mod test_export_copy;
+    mod test_export_csv;
+    mod test_export_json;
+    mod test_export_ods;
+    mod test_export_sql;
+    mod test_export_zip;
+}
 
 pub mod sqlite {
     mod test_sqlite_affinity;
+    mod test_sqlite_database;
+    mod test_sqlite_fingerprint;
+    mod test_sqlite_jump;
+    mod test_sqlite_range;
+    mod test_sqlite_row;
 }
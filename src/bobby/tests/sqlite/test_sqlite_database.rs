@@ -0,0 +1,96 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use gio::File;
+use gio::prelude::FileExt;
+use rusqlite::Connection;
+
+use crate::bobby::prelude::*;
+
+
+fn test_dir(name: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let dir = env::temp_dir().join(format!("bobby-test-{}-{name}", process::id()));
+
+    _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+
+    Ok(dir)
+}
+
+
+#[test]
+fn test_sqlite_database_copy_to() -> Result<(), Box<dyn Error>> {
+    let dir = test_dir("copy-to")?;
+    let copies = dir.join("copies");
+
+    for name in ["a", "b"] {
+        fs::create_dir_all(dir.join(name))?;
+        fs::write(dir.join(name).join("app.db"), name)?;
+    }
+
+    let a = Database::copy_to(&File::for_path(dir.join("a/app.db")), &copies)?;
+    let b = Database::copy_to(&File::for_path(dir.join("b/app.db")), &copies)?;
+
+    // Same file name, but not the same file
+    assert_ne!(a.path(), b.path());
+    assert_eq!(fs::read_to_string(a.path().unwrap_or_default())?, "a");
+    assert_eq!(fs::read_to_string(b.path().unwrap_or_default())?, "b");
+
+    // Copying again replaces the copy
+    let again = Database::copy_to(&File::for_path(dir.join("a/app.db")), &copies)?;
+    assert_eq!(again.path(), a.path());
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
+
+
+#[test]
+fn test_sqlite_database_snapshot() -> Result<(), Box<dyn Error>> {
+    let dir = test_dir("snapshot")?;
+    let snapshots = dir.join("snapshots");
+
+    // Keep the WAL content by not closing the connection
+    let writer = Connection::open(dir.join("wal.db"))?;
+    writer.execute_batch("
+        PRAGMA journal_mode = WAL;
+        CREATE TABLE t (name TEXT);
+        INSERT INTO t VALUES ('a');
+    ")?;
+
+    // WAL content without the shared memory file can't be read in place
+    let [path, wal, _] = Database::sidecar_paths(&dir.join("copy.db"));
+    fs::copy(dir.join("wal.db"), &path)?;
+    fs::copy(dir.join("wal.db-wal"), &wal)?;
+
+    let file = File::for_path(&path);
+    let database = Database::from_file(&file, None, &snapshots)?;
+    let other = Database::from_file(&file, None, &snapshots)?;
+
+    assert_eq!(database.open_mode, OpenMode::Snapshot);
+    assert_eq!(database.tables()?.len(), 1);
+    assert!(fs::read_dir(&snapshots)?.count() >= 2);
+
+    // Each has its own, removed along with the last clone
+    let clone = database.clone();
+    drop(database);
+    drop(other);
+    assert_eq!(clone.tables()?.len(), 1);
+
+    drop(clone);
+    assert_eq!(fs::read_dir(&snapshots)?.count(), 0);
+
+    drop(writer);
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+use std::hash::{ DefaultHasher, Hasher };
+
+use rusqlite::types::ValueRef;
+
+use crate::bobby::prelude::*;
+use crate::bobby::sqlite::fingerprint::hash_value;
+
+
+fn row(rowid: i64, text: &str) -> Row {
+    let mut hasher = DefaultHasher::new();
+    hash_value(ValueRef::Text(text.as_bytes()), &mut hasher);
+
+    Row {
+        cells: vec![Affinity::INTEGER(Some(rowid)), Affinity::TEXT(Some(text.into()))],
+        rowid: Some(rowid),
+        change: None,
+        fingerprint: hasher.finish(),
+    }
+}
+
+
+#[test]
+fn test_sqlite_fingerprint_compare() {
+    let mut fingerprints = Fingerprints::new(&Table::default(), Some(20));
+    fingerprints.record(&[row(1, "a"), row(2, "b"), row(4, "d")]);
+
+    assert_eq!(fingerprints.compare(&row(1, "a")), None);
+    assert_eq!(fingerprints.compare(&row(2, "B")), Some(RowChange::Modified));
+    assert_eq!(fingerprints.compare(&row(3, "c")), Some(RowChange::Inserted)); // Gap in a seen page
+    assert_eq!(fingerprints.compare(&row(10, "j")), None); // Never seen
+    assert_eq!(fingerprints.compare(&row(21, "u")), Some(RowChange::Inserted));
+}
+
+
+#[test]
+fn test_sqlite_fingerprint_blob() -> Result<(), Box<dyn Error>> {
+    let database = Database::default();
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (data BLOB);
+         INSERT INTO t VALUES (X'00000000000000000001');"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let sql = "SELECT rowid, * FROM t;";
+
+    let mut fingerprints = Fingerprints::new(&table, Some(1));
+    fingerprints.record(&database.query_rows(sql, true)?);
+
+    // Only differs after the part of the blob that's shown
+    database.connection.borrow().execute_batch("UPDATE t SET data = X'00000000000000000002';")?;
+
+    let rows = database.query_rows(sql, true)?;
+    let row = rows.first().ok_or("No row")?;
+
+    assert_eq!(fingerprints.compare(row), Some(RowChange::Modified));
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use crate::bobby::prelude::*;
+use crate::bobby::sqlite::jump::key_values;
+
+
+#[test]
+fn test_sqlite_jump_row_target() {
+    assert_eq!(RowTarget::new("number", " 12 "), Ok(RowTarget::Number(12)));
+    assert_eq!(RowTarget::new("number", "#3"), Ok(RowTarget::Number(3)));
+    assert!(RowTarget::new("number", "0").is_err());
+    assert!(RowTarget::new("number", "-1").is_err());
+
+    assert_eq!(RowTarget::new("rowid", "-7"), Ok(RowTarget::Rowid(-7)));
+    assert!(RowTarget::new("rowid", "seven").is_err());
+
+    assert_eq!(RowTarget::new("key", "ABC-1"), Ok(RowTarget::Key("ABC-1".into())));
+    assert!(RowTarget::new("key", "  ").is_err());
+    assert!(RowTarget::new("page", "1").is_err());
+
+    assert_eq!(RowTarget::Number(12).to_string(), "row 12");
+    assert_eq!(RowTarget::Key("a".into()).to_string(), "key ‘a’");
+}
+
+
+#[test]
+fn test_sqlite_jump_key_values() {
+    assert_eq!(key_values("a, b", 1), Some(vec!["a, b".to_string()]));
+    assert_eq!(key_values("1, 2", 2), Some(vec!["1".to_string(), "2".to_string()]));
+    assert_eq!(key_values("1", 2), None);
+    assert_eq!(key_values("1", 0), None);
+}
+
+
+#[test]
+fn test_sqlite_jump_key_position() -> Result<(), Box<dyn Error>> {
+    let database = Database::default();
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (a INTEGER, b TEXT, c, PRIMARY KEY (b, a DESC)) WITHOUT ROWID;
+         INSERT INTO t VALUES (1, 'x', 0), (2, 'x', 0), (1, 'y', 0), (3, 'w', 0);"
+    )?;
+
+    let table = database.tables()?.into_iter().next().ok_or("No table")?;
+    let position = |key: &str| database.row_target_position(&table, &RowTarget::Key(key.into()));
+
+    // Stored as (w, 3), (x, 2), (x, 1), (y, 1)
+    assert_eq!(position("w, 3")?, Some(0));
+    assert_eq!(position("x, 2")?, Some(1));
+    assert_eq!(position("x, 1")?, Some(2));
+    assert_eq!(position("y, 1")?, Some(3));
+    assert_eq!(position("1, y")?, None);
+
+    Ok(())
+}
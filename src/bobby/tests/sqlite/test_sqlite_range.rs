@@ -0,0 +1,79 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use crate::bobby::prelude::*;
+
+
+#[test]
+fn test_sqlite_range_extend() {
+    let mut range = CellRange::new(5, 1);
+    assert!(range.is_single());
+
+    range.extend(-2, 1, 10, 3);
+    assert_eq!(range.rows(), 3..=5);
+    assert_eq!(range.columns(), 1..=2);
+    assert!(range.contains(4, 2));
+    assert!(!range.contains(6, 1));
+
+    // Stays within the table
+    range.extend(-10, 10, 10, 3);
+    assert_eq!(range.cursor, (0, 2));
+}
+
+
+#[test]
+fn test_sqlite_range_pressed() {
+    // Alt range followed by a plain selection
+    let mut range = CellRange::pressed(Some((2, 0)), true);
+    assert_eq!(range, Some(CellRange::new(2, 0)));
+
+    if let Some(range) = range.as_mut() {
+        range.extend(3, 1, 10, 3);
+    }
+
+    assert_eq!(range.map(|range| range.is_single()), Some(false));
+
+    range = CellRange::pressed(Some((7, 1)), false);
+    assert_eq!(range, None);
+
+    assert_eq!(CellRange::pressed(None, true), None);
+}
+
+
+#[test]
+fn test_sqlite_range_format_cells_with() {
+    let row = |id, name: &str, note: &str| Row {
+        cells: vec![
+            Affinity::INTEGER(Some(id)),
+            Affinity::TEXT(Some(name.into())),
+            Affinity::TEXT(Some(note.into())),
+        ],
+        ..Default::default()
+    };
+
+    let rows = vec![row(1, "a", "x"), row(2, "b", "y")];
+
+    let columns = ["id", "name", "note"]
+        .map(|name| Column { name: name.into(), ..Default::default() })
+        .to_vec();
+
+    assert_eq!(
+        Row::format_cells_with(&rows, &columns, &[1, 2], ColumnSeparator::Commas),
+        "a,x\nb,y"
+    );
+
+    // Reordered
+    assert_eq!(
+        Row::format_cells_with(&rows, &columns, &[2, 0], ColumnSeparator::Commas),
+        "x,1\ny,2"
+    );
+
+    assert_eq!(
+        Row::format_cells_with(&rows, &columns, &[0], ColumnSeparator::Markdown),
+        "| id |\n| --- |\n| 1 |\n| 2 |"
+    );
+}
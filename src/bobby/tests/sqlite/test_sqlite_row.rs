@@ -0,0 +1,71 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use crate::bobby::prelude::*;
+
+
+fn rows() -> Vec<Row> {
+    vec![
+        Row { cells: vec![Affinity::INTEGER(Some(1)), Affinity::TEXT(Some("a".into()))], ..Default::default() },
+        Row { cells: vec![Affinity::INTEGER(Some(2)), Affinity::TEXT(Some("b".into()))], ..Default::default() },
+    ]
+}
+
+fn columns() -> Vec<Column> {
+    vec![
+        Column { name: "id".into(), ..Default::default() },
+        Column { name: "name".into(), ..Default::default() },
+    ]
+}
+
+
+#[test]
+fn test_sqlite_row_format_rows_with() {
+    assert_eq!(
+        Row::format_rows_with(&rows(), &columns(), ColumnSeparator::Tabs),
+        "1\ta\n2\tb"
+    );
+
+    assert_eq!(
+        Row::format_rows_with(&rows(), &columns(), ColumnSeparator::Markdown),
+        "| id | name |\n| --- | --- |\n| 1 | a |\n| 2 | b |"
+    );
+}
+
+
+#[test]
+fn test_sqlite_row_rows_next_to() -> Result<(), Box<dyn Error>> {
+    let mut database = Database::default();
+
+    database.connection.borrow().execute_batch(
+        "CREATE TABLE t (name TEXT);
+         INSERT INTO t (rowid, name) VALUES (1, 'a'), (5, 'b'), (9, 'c'), (20, 'd');"
+    )?;
+
+    let tables = database.tables()?;
+    let table = tables.first().ok_or("No table")?;
+
+    let rowids = |rows: Vec<Row>| rows.iter().filter_map(|row| row.rowid).collect::<Vec<_>>();
+
+    database.row_order = Some(RowOrder::Ascending);
+
+    // Rowids have gaps, so positions and rowids differ
+    assert_eq!(rowids(database.rows(table, Some(1), Some(2))?), [5, 9]);
+    assert_eq!(rowids(database.rows_next_to(table, 5, true, 2)?), [9, 20]);
+    assert_eq!(rowids(database.rows_next_to(table, 9, false, 5)?), [1, 5]);
+    assert_eq!(rowids(database.rows_at(table, &[(0, 1), (2, 2)])?), [1, 9, 20]);
+
+    database.row_order = Some(RowOrder::Descending);
+
+    assert_eq!(rowids(database.rows(table, Some(1), Some(2))?), [9, 5]);
+    assert_eq!(rowids(database.rows_next_to(table, 9, true, 5)?), [5, 1]);
+    assert_eq!(rowids(database.rows_next_to(table, 5, false, 1)?), [9]);
+
+    Ok(())
+}
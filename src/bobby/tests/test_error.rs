@@ -0,0 +1,60 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::io;
+use std::path::PathBuf;
+
+use rusqlite::ffi;
+
+use crate::bobby::Error;
+
+
+fn sqlite_error(code: i32) -> rusqlite::Error {
+    rusqlite::Error::SqliteFailure(ffi::Error::new(code), None)
+}
+
+
+#[test]
+fn test_error_from_open() {
+    let path = PathBuf::from("/tmp/test.db");
+
+    assert!(matches!(Error::from_open(path.clone(), sqlite_error(ffi::SQLITE_BUSY)), Error::Locked(_, _)));
+    assert!(matches!(Error::from_open(path.clone(), sqlite_error(ffi::SQLITE_NOTADB)), Error::NotADatabase(_)));
+    assert!(matches!(Error::from_open(path.clone(), sqlite_error(ffi::SQLITE_CORRUPT)), Error::Corrupt(_, _)));
+    assert!(matches!(Error::from_open(path, sqlite_error(ffi::SQLITE_CANTOPEN)), Error::Open(_, _)));
+}
+
+
+#[test]
+fn test_error_from_metadata() {
+    let path = PathBuf::from("/tmp/test.db");
+
+    let e = Error::from_metadata(path.clone(), io::Error::from(io::ErrorKind::NotFound));
+    assert!(matches!(e, Error::NotFound(_)));
+    assert!(e.is_transient());
+    assert_eq!(e.to_string(), "test.db does not exist");
+
+    let e = Error::from_metadata(path, io::Error::from(io::ErrorKind::PermissionDenied));
+    assert!(matches!(e, Error::PermissionDenied(_, _)));
+    assert!(!e.is_transient());
+}
+
+
+#[test]
+fn test_error_from_sqlite() {
+    // Raised while browsing, after the file was opened
+    let e = Error::from(sqlite_error(ffi::SQLITE_BUSY));
+    assert!(matches!(e, Error::Locked(None, _)));
+    assert_eq!(e.title(), "File Is Locked");
+    assert!(e.to_string().starts_with("The database is locked by another program"));
+
+    let e = Error::from_open(PathBuf::from("/tmp/test.db"), sqlite_error(ffi::SQLITE_CORRUPT));
+    assert!(e.to_string().starts_with("test.db is damaged"));
+
+    assert!(matches!(Error::from(sqlite_error(ffi::SQLITE_CORRUPT)), Error::Corrupt(None, _)));
+    assert!(matches!(Error::from(sqlite_error(ffi::SQLITE_ERROR)), Error::Sqlite(_)));
+}
@@ -0,0 +1,28 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use crate::bobby::fuzzy::fuzzy_score;
+
+
+#[test]
+fn test_fuzzy_score() {
+    assert_eq!(fuzzy_score("", "orders"), Some(0));
+    assert_eq!(fuzzy_score("sro", "orders"), None);
+    assert_eq!(fuzzy_score("xyz", "orders"), None);
+
+    assert!(fuzzy_score("ORD", "orders").is_some());
+    assert!(fuzzy_score("order items", "order_items").is_some());
+
+    // Word starts and runs beat scattered matches
+    assert!(fuzzy_score("oi", "order_items") > fuzzy_score("oi", "notice"));
+    assert!(fuzzy_score("ci", "copyItem") > fuzzy_score("ci", "mockingbird"));
+    assert!(fuzzy_score("ord", "orders") > fuzzy_score("ord", "word_order_log"));
+    assert!(fuzzy_score("items", "word items") > fuzzy_score("items", "i_t_e_m_s"));
+
+    // Shorter wins when the matches are the same
+    assert!(fuzzy_score("users", "users") > fuzzy_score("users", "users_archive"));
+}
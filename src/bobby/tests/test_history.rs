@@ -0,0 +1,60 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use crate::bobby::history::History;
+
+
+#[test]
+fn test_history_back_and_forward() {
+    let mut history = History::default();
+    assert!(!history.can_go_back());
+    assert_eq!(history.back("a"), None);
+
+    // a → b → c
+    history.visit("a");
+    history.visit("b");
+
+    assert_eq!(history.back("c"), Some("b"));
+    assert_eq!(history.back("b"), Some("a"));
+    assert_eq!(history.back("a"), None);
+    assert!(history.can_go_forward());
+
+    assert_eq!(history.forward("a"), Some("b"));
+    assert_eq!(history.forward("b"), Some("c"));
+    assert_eq!(history.forward("c"), None);
+}
+
+
+#[test]
+fn test_history_visit() {
+    let mut history = History::default();
+
+    history.visit(1);
+    history.visit(1); // Same place twice
+    history.visit(2);
+
+    // Going somewhere new drops the way forward
+    assert_eq!(history.back(3), Some(2));
+    history.visit(2);
+    assert!(!history.can_go_forward());
+
+    assert_eq!(history.back(4), Some(2));
+    assert_eq!(history.back(2), Some(1));
+    assert!(!history.can_go_back());
+
+    for i in 0..1000 {
+        history.visit(i);
+    }
+
+    let mut count = 0;
+
+    while history.back(0).is_some() {
+        count += 1;
+    }
+
+    assert_eq!(count, 100);
+}
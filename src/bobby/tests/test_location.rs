@@ -0,0 +1,71 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::PathBuf;
+
+use crate::bobby::prelude::*;
+
+
+#[test]
+fn test_location_from_str() -> Result<(), String> {
+    let location = "file.db#orders:1234".parse::<Location>()?;
+    assert_eq!(location.path, PathBuf::from("file.db"));
+    assert_eq!(location.table_name.as_deref(), Some("orders"));
+    assert_eq!(location.rowid, Some(1234));
+
+    let location = "file.db#a:b".parse::<Location>()?;
+    assert_eq!(location.table_name.as_deref(), Some("a:b"));
+    assert_eq!(location.rowid, None);
+
+    let location = "file.db#:7".parse::<Location>()?;
+    assert_eq!(location.table_name, None);
+    assert_eq!(location.rowid, Some(7));
+
+    let location = "file.db".parse::<Location>()?;
+    assert!(!location.has_target());
+
+    assert!("#orders".parse::<Location>().is_err());
+
+    Ok(())
+}
+
+
+#[test]
+fn test_location_uri() -> Result<(), String> {
+    let location = Location {
+        path: PathBuf::from("/home/me/My Files/shop.db"),
+        table_name: Some("order items".to_string()),
+        rowid: Some(12),
+        column: Some("status&co".to_string()),
+    };
+
+    let uri = location.to_uri();
+    assert_eq!(uri, "bobby:///home/me/My%20Files/shop.db?table=order%20items&rowid=12&column=status%26co");
+    assert_eq!(Location::from_uri(&uri)?, location);
+    assert_eq!(uri.parse::<Location>()?, location);
+
+    let location = Location::from_uri("bobby://localhost/tmp/a.db?table=x+y&unknown=1")?;
+    assert_eq!(location.path, PathBuf::from("/tmp/a.db"));
+    assert_eq!(location.table_name.as_deref(), Some("x y"));
+
+    assert_eq!(Location::new("/tmp/a.db").to_uri(), "bobby:///tmp/a.db");
+    assert!(Location::from_uri("bobby://elsewhere/tmp/a.db").is_err());
+    assert!(Location::from_uri("file:///tmp/a.db").is_err());
+
+    Ok(())
+}
+
+
+#[test]
+fn test_location_query() {
+    let location = Location::default().with_query("table=orders&rowid=1234");
+    assert_eq!(location.table_name.as_deref(), Some("orders"));
+    assert_eq!(location.rowid, Some(1234));
+    assert_eq!(location.query(), "table=orders&rowid=1234");
+
+    assert!(!Location::default().with_query("").has_target());
+}
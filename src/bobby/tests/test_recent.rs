@@ -0,0 +1,61 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::{ Path, PathBuf };
+
+use crate::bobby::recent::{ RecentFile, RecentFiles };
+
+
+fn recent_file(path: &str, table_name: Option<&str>) -> RecentFile {
+    RecentFile {
+        path: PathBuf::from(path),
+        table_name: table_name.map(String::from),
+        opened: 1_700_000_000,
+        size: 4096,
+    }
+}
+
+
+#[test]
+fn test_recent_files_add() {
+    let mut recent = RecentFiles::default();
+
+    recent.add(recent_file("/a.db", Some("orders")));
+    recent.add(recent_file("/b.db", None));
+    recent.add(recent_file("/a.db", None)); // Opened again
+
+    let paths = recent.files().iter().map(|f| f.path.clone()).collect::<Vec<_>>();
+    assert_eq!(paths, [PathBuf::from("/a.db"), PathBuf::from("/b.db")]);
+
+    // Kept the table it was left at
+    assert_eq!(recent.files()[0].table_name.as_deref(), Some("orders"));
+
+    assert!(recent.set_table(Path::new("/b.db"), "users"));
+    assert!(!recent.set_table(Path::new("/b.db"), "users"));
+    assert!(!recent.set_table(Path::new("/c.db"), "users"));
+    assert_eq!(recent.files()[1].table_name.as_deref(), Some("users"));
+
+    assert!(recent.remove(Path::new("/a.db")).is_some());
+    assert!(recent.remove(Path::new("/a.db")).is_none());
+    assert_eq!(recent.files().len(), 1);
+}
+
+
+#[test]
+fn test_recent_files_round_trip() {
+    let mut recent = RecentFiles::default();
+
+    recent.add(recent_file("/data/plain.db", None));
+    recent.add(recent_file("/data/odd\tname\\.db", Some("line\nbreak")));
+
+    let text = recent.to_string();
+    assert_eq!(RecentFiles::parse(&text), recent);
+
+    // Lines that don't read are skipped
+    let text = format!("garbage\n{text}1\tbig\t\t/x.db\n");
+    assert_eq!(RecentFiles::parse(&text), recent);
+}
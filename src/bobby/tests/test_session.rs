@@ -0,0 +1,62 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::PathBuf;
+
+use crate::bobby::session::{ position_runs, Session, TabSession, WindowSession };
+
+
+fn tab_session(table_name: &str, is_selected: bool) -> TabSession {
+    TabSession {
+        table_name: table_name.into(),
+        is_selected,
+        h_value: 0.0,
+        v_value: 1250.5,
+        selection: vec![(3, 2), (10, 1)],
+    }
+}
+
+
+#[test]
+fn test_session_round_trip() {
+    let session = Session {
+        windows: vec![
+            WindowSession {
+                path: PathBuf::from("/data/shop.db"),
+                width: 960,
+                height: 640,
+                maximized: false,
+                tabs: vec![tab_session("orders", false), tab_session("odd\ttable", true)],
+            },
+            WindowSession {
+                path: PathBuf::from("/data/empty.db"),
+                width: 1200,
+                height: 800,
+                maximized: true,
+                tabs: Vec::new(),
+            },
+        ],
+    };
+
+    let text = session.to_string();
+    assert_eq!(Session::parse(&text), session);
+
+    assert_eq!(session.windows[0].table_name(), Some("odd\ttable"));
+    assert_eq!(session.windows[1].table_name(), None);
+
+    // Tabs without a window and lines that don't read are skipped
+    let text = format!("tab\t1\t0\t0\t\torphan\nwindow\tbig\t1\t0\t/x.db\n{text}tab\t0\t0\t0\t1+\tbroken\n");
+    assert_eq!(Session::parse(&text), session);
+}
+
+
+#[test]
+fn test_session_position_runs() {
+    assert_eq!(position_runs(&[]), []);
+    assert_eq!(position_runs(&[4]), [(4, 1)]);
+    assert_eq!(position_runs(&[0, 1, 2, 5, 7, 8]), [(0, 3), (5, 1), (7, 2)]);
+}
@@ -0,0 +1,55 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::{ Path, PathBuf };
+
+use crate::bobby::view::{ ColumnSettings, TableSettings, ViewSettings };
+
+
+fn table_settings(table_name: &str, fingerprint: u64) -> TableSettings {
+    TableSettings {
+        path: PathBuf::from("/data/shop.db"),
+        table_name: table_name.into(),
+        fingerprint,
+        columns: vec![
+            ColumnSettings { name: "id".into(), width: 80, hidden: false },
+            ColumnSettings { name: "odd\tname".into(), width: 240, hidden: true },
+        ],
+    }
+}
+
+
+#[test]
+fn test_view_settings_tables() {
+    let path = Path::new("/data/shop.db");
+    let mut settings = ViewSettings::default();
+
+    assert!(settings.set_table(table_settings("orders", 1)));
+    assert!(!settings.set_table(table_settings("orders", 1))); // Nothing new
+    assert!(settings.table(path, "orders", 1).is_some());
+
+    // Columns changed since
+    assert!(settings.table(path, "orders", 2).is_none());
+    assert!(settings.set_table(table_settings("orders", 2)));
+    assert!(settings.table(path, "orders", 1).is_none());
+}
+
+
+#[test]
+fn test_view_settings_round_trip() {
+    let mut settings = ViewSettings::default();
+
+    settings.set_table(table_settings("orders", 0xdeadbeef));
+    settings.set_table(table_settings("users", u64::MAX));
+
+    let text = settings.to_string();
+    assert_eq!(ViewSettings::parse(&text), settings);
+
+    // Columns without a table and lines that don't read are skipped
+    let text = format!("column\t10\t0\torphan\ntable\tnope\tx\t/x.db\n{text}column\twide\t0\tbroken\n");
+    assert_eq!(ViewSettings::parse(&text), settings);
+}
@@ -0,0 +1,161 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it under
+//   the terms of the GNU General Public License v3 or any later version.
+
+
+use std::fmt;
+use std::path::{ Path, PathBuf };
+
+use crate::bobby::Error;
+use crate::bobby::escape::{ escape, unescape };
+use crate::bobby::state::{ state_read, state_write };
+
+
+const MAX_TABLES: usize = 500;
+
+
+/// How a column was left: its width, and whether it was hidden
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnSettings {
+    pub name: String,
+    pub width: i32,
+    pub hidden: bool,
+}
+
+
+/// How a table was left, with its columns in the order they were shown in.
+/// Only applies while the columns stay the same, which `fingerprint` tells.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSettings {
+    pub path: PathBuf,
+    pub table_name: String,
+    pub fingerprint: u64,
+    pub columns: Vec<ColumnSettings>,
+}
+
+
+/// View settings of every table looked at
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ViewSettings {
+    /// The least recently changed first
+    tables: Vec<TableSettings>,
+}
+
+
+impl ViewSettings {
+    /// Reads the settings kept at `path`, which are empty when there's no file yet
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        Ok(Self::parse(&state_read(path)?))
+    }
+
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        state_write(path, &self.to_string())
+    }
+
+
+    /// A "table" line for each table, followed by a "column" line for each of
+    /// its columns. Lines that don't read are skipped, as are columns without a table.
+    pub fn parse(text: &str) -> Self {
+        let mut settings = Self::default();
+
+        for line in text.lines() {
+            let mut fields = line.splitn(2, '\t');
+
+            match (fields.next(), fields.next()) {
+                (Some("table"), Some(rest)) => {
+                    if let Some(table) = parse_table(rest) {
+                        settings.tables.push(table);
+                    }
+                },
+                (Some("column"), Some(rest)) => {
+                    if let Some(table) = settings.tables.last_mut() &&
+                       let Some(column) = parse_column(rest)
+                    {
+                        table.columns.push(column);
+                    }
+                },
+                _ => {},
+            }
+        }
+
+        settings
+    }
+
+
+    /// The settings of `table_name` in `path`, if its columns are still the same
+    pub fn table(&self, path: &Path, table_name: &str, fingerprint: u64) -> Option<&TableSettings> {
+        self.tables.iter().find(|table| {
+            table.path == path &&
+            table.table_name == table_name &&
+            table.fingerprint == fingerprint
+        })
+    }
+
+
+    /// Replaces the settings of the table, also those from before its columns changed
+    pub fn set_table(&mut self, settings: TableSettings) -> bool {
+        if self.table(&settings.path, &settings.table_name, settings.fingerprint) == Some(&settings) {
+            return false;
+        }
+
+        self.tables.retain(|table| table.path != settings.path || table.table_name != settings.table_name);
+        self.tables.push(settings);
+
+        if self.tables.len() > MAX_TABLES {
+            self.tables.remove(0);
+        }
+
+        true
+    }
+}
+
+
+/// "fingerprint\ttable\tpath"
+fn parse_table(text: &str) -> Option<TableSettings> {
+    let mut fields = text.splitn(3, '\t');
+
+    Some(TableSettings {
+        fingerprint: u64::from_str_radix(fields.next()?, 16).ok()?,
+        table_name: unescape(fields.next()?),
+        path: PathBuf::from(unescape(fields.next()?)),
+        columns: Vec::new(),
+    })
+}
+
+
+/// "width\thidden\tname"
+fn parse_column(text: &str) -> Option<ColumnSettings> {
+    let mut fields = text.splitn(3, '\t');
+
+    Some(ColumnSettings {
+        width: fields.next()?.parse().ok()?,
+        hidden: fields.next()? == "1",
+        name: unescape(fields.next()?),
+    })
+}
+
+
+impl fmt::Display for ViewSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for table in &self.tables {
+            writeln!(f, "table\t{:016x}\t{}\t{}",
+                table.fingerprint,
+                escape(&table.table_name),
+                escape(&table.path.to_string_lossy()),
+            )?;
+
+            for column in &table.columns {
+                writeln!(f, "column\t{}\t{}\t{}",
+                    column.width,
+                    u8::from(column.hidden),
+                    escape(&column.name),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -6,9 +6,24 @@
 
 
 use std::error::Error;
+use std::io;
+use std::io::{ BufWriter, Write };
+use std::path::Path;
 use std::process::exit;
 
+use gio::prelude::FileExt;
+use rusqlite::types::ValueRef;
+
 use crate::app::{ app_deps, app_version, App };
+use crate::bobby;
+use crate::bobby::prelude::*;
+use crate::bobby::export::csv::CsvOptions;
+use crate::bobby::export::encoding::hex;
+use crate::bobby::sqlite::database::SNAPSHOTS_DIR;
+
+
+/// Subcommands that print what's in a file without starting the GUI
+const COMMANDS: [&str; 4] = ["tables", "schema", "count", "rows"];
 
 
 impl App {
@@ -26,10 +41,226 @@ impl App {
 
 
     pub fn cli_option_help(&self) {
-        println!("Usage: bobby [file]...");
+        println!("Usage: bobby [file[#table[:rowid]]]... [--table TABLE] [--row ROWID]");
+        println!("       bobby tables FILE");
+        println!("       bobby schema FILE [TABLE]");
+        println!("       bobby count FILE TABLE");
+        println!("       bobby rows FILE TABLE [--limit N] [--format csv|json|md]");
         println!();
         println!("Options:");
         println!("    --help, --version, --deps, --env");
         println!();
     }
+
+
+    /// Reads the files to open, with the table and row to show. Options
+    /// apply to the file before them, or the next one when there's none.
+    pub fn cli_open_locations(&self, args: &Vec<String>) -> Result<Vec<Location>, Box<dyn Error>> {
+        let mut locations: Vec<Location> = Vec::new();
+        let mut pending = Location::default();
+        let mut args = args.iter().skip(1);
+
+        while let Some(arg) = args.next() {
+            let target = match locations.last_mut() {
+                Some(location) => location,
+                None => &mut pending,
+            };
+
+            match arg.as_str() {
+                "--table" => {
+                    target.table_name = Some(args.next().ok_or("Missing value for --table")?.clone());
+                },
+                "--row" => {
+                    let value = args.next().ok_or("Missing value for --row")?;
+                    target.rowid = Some(value.parse::<i64>().map_err(|_| format!("Invalid row: {value}"))?);
+                },
+                // Names with a '#' in them are files too
+                arg if Path::new(arg).exists() => locations.push(Location {
+                    path: arg.into(),
+                    ..pending.clone()
+                }),
+                arg if arg.starts_with("file://") => locations.push(Location {
+                    path: gio::File::for_uri(arg).path().ok_or(format!("Not a local file: {arg}"))?,
+                    ..pending.clone()
+                }),
+                arg => {
+                    let location = arg.parse::<Location>()?;
+
+                    locations.push(Location {
+                        table_name: location.table_name.or(pending.table_name.clone()),
+                        rowid: location.rowid.or(pending.rowid),
+                        ..location
+                    });
+                },
+            }
+        }
+
+        Ok(locations)
+    }
+
+
+    /// Runs the subcommand in `args`, if there is one
+    pub fn cli_run_command(&self, args: &Vec<String>) -> Option<Result<(), Box<dyn Error>>> {
+        let command = args.get(1)?.as_str();
+
+        if !COMMANDS.contains(&command) {
+            return None;
+        }
+
+        let args = args.get(2..).unwrap_or_default();
+        let stdout = io::stdout();
+        let mut writer = BufWriter::new(stdout.lock());
+
+        let snapshots = self.app_cache_home.join(SNAPSHOTS_DIR);
+
+        let result = cli_command(command, args, &snapshots, &mut writer)
+            .and_then(|_| Ok(writer.flush()?));
+
+        // Piped into something like head, which stopped reading
+        match result {
+            Err(e) if is_broken_pipe(e.as_ref()) => Some(Ok(())),
+            result => Some(result),
+        }
+    }
+}
+
+
+fn cli_command(
+    command: &str,
+    args: &[String],
+    snapshots: &Path,
+    writer: &mut impl Write,
+) -> Result<(), Box<dyn Error>>
+{
+    let path = args.first().ok_or("Missing FILE")?;
+    let file = gio::File::for_path(path);
+
+    // Oldest first, like the output of sqlite3
+    let database = Database::from_file(&file, Some(RowOrder::Ascending), snapshots)?;
+
+    match command {
+        "tables" => {
+            for table in database.tables()? {
+                writeln!(writer, "{}", table.name())?;
+            }
+        },
+        "schema" => {
+            let table = args.get(1)
+                .map(|name| cli_table(&database, name))
+                .transpose()?;
+
+            for statement in database.schema(table.as_ref())? {
+                writeln!(writer, "{statement}")?;
+            }
+        },
+        "count" => {
+            let table = cli_table(&database, args.get(1).ok_or("Missing TABLE")?)?;
+            writeln!(writer, "{}", database.row_count(&table)?)?;
+        },
+        "rows" => {
+            let table = cli_table(&database, args.get(1).ok_or("Missing TABLE")?)?;
+            let (limit, format) = cli_rows_options(args.get(2..).unwrap_or_default())?;
+
+            cli_rows(&database, &table, limit, format, writer)?;
+        },
+        _ => return Err(format!("Unknown command: {command}").into()),
+    }
+
+    Ok(())
+}
+
+
+fn cli_table(database: &Database, name: &str) -> Result<Table, Box<dyn Error>> {
+    database.tables()?
+        .into_iter()
+        .find(|table| table.name() == name)
+        .ok_or(format!("No table named ‘{name}’").into())
+}
+
+
+fn cli_rows_options(args: &[String]) -> Result<(Option<u64>, &str), Box<dyn Error>> {
+    let mut limit = None;
+    let mut format = "csv";
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--limit" => {
+                let value = args.next().ok_or("Missing value for --limit")?;
+                limit = Some(value.parse::<u64>().map_err(|_| format!("Invalid limit: {value}"))?);
+            },
+            "--format" => match args.next().map(|s| s.as_str()) {
+                Some(value @ ("csv" | "json" | "md")) => format = value,
+                Some(value) => return Err(format!("Unknown format: {value}").into()),
+                None => return Err("Missing value for --format".into()),
+            },
+            _ => return Err(format!("Unknown option: {arg}").into()),
+        }
+    }
+
+    Ok((limit, format))
+}
+
+
+fn cli_rows(
+    database: &Database,
+    table: &Table,
+    limit: Option<u64>,
+    format: &str,
+    writer: &mut impl Write,
+) -> Result<(), Box<dyn Error>>
+{
+    match format {
+        "json" => _ = database.export_json(table, writer, limit)?,
+        "md" => {
+            let names = database.columns(table)?
+                .iter()
+                .map(|column| md_field(&column.name))
+                .collect::<Vec<_>>();
+
+            writeln!(writer, "| {} |", names.join(" | "))?;
+            writeln!(writer, "| {} |", vec!["---"; names.len()].join(" | "))?;
+
+            database.for_each_row_limit(table, limit, |row, n_columns| {
+                let mut fields = Vec::with_capacity(n_columns);
+
+                for i in 0..n_columns {
+                    let field = match row.get_ref(i)? {
+                        ValueRef::Null       => String::new(),
+                        ValueRef::Integer(i) => i.to_string(),
+                        ValueRef::Real(f)    => f.to_string(),
+                        ValueRef::Text(t)    => md_field(&String::from_utf8_lossy(t)),
+                        ValueRef::Blob(b)    => hex(b),
+                    };
+
+                    fields.push(field);
+                }
+
+                writeln!(writer, "| {} |", fields.join(" | "))?;
+                Ok(())
+            })?;
+        },
+        _ => _ = database.export_csv(table, writer, &CsvOptions::default(), limit)?,
+    }
+
+    Ok(())
+}
+
+
+/// Escapes pipes and keeps the cell on a single line
+fn md_field(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+
+fn is_broken_pipe(e: &(dyn Error + 'static)) -> bool {
+    let kind = match e.downcast_ref::<bobby::Error>() {
+        Some(bobby::Error::Io(e)) => Some(e.kind()),
+        _ => e.downcast_ref::<io::Error>().map(|e| e.kind()),
+    };
+
+    kind == Some(io::ErrorKind::BrokenPipe)
 }
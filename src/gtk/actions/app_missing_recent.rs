@@ -0,0 +1,19 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::glib::VariantTy;
+
+
+/// Stands in for opening recent files that have gone missing,
+/// and is never enabled, so they show greyed out in menus
+pub fn missing_recent_action() -> SimpleAction {
+    let action = SimpleAction::new("missing-recent", Some(VariantTy::STRING));
+    action.set_enabled(false);
+
+    action
+}
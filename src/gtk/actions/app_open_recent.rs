@@ -0,0 +1,38 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::Path;
+
+use gio::{ File, SimpleAction };
+use gtk4::glib::VariantTy;
+use libadwaita::Application;
+
+use crate::bobby::prelude::*;
+use crate::gtk::windows::prelude::*;
+use crate::log;
+
+
+/// Opens a recent file at the table it was left at, with its path as the parameter
+pub fn open_recent_action(app: &Application) -> SimpleAction {
+    let action = SimpleAction::new("open-recent", Some(VariantTy::STRING));
+    let app_handle = app.clone();
+
+    action.connect_activate(move |_, parameter| {
+        let Some(path) = parameter.and_then(|v| v.str()).map(Path::new) else {
+            return;
+        };
+
+        // Which goes back to the table it was left at
+        let target = Location::new(path);
+
+        if let Err(e) = window_handle_open(&app_handle, &File::for_path(path), &target) {
+            log::error(&e.to_string());
+        }
+    });
+
+    action
+}
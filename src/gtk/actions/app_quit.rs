@@ -9,6 +9,8 @@ use gio::SimpleAction;
 use gtk4::prelude::*;
 use libadwaita::Application;
 
+use crate::gtk::session::session_save;
+
 
 pub fn quit_action(app: &Application) -> SimpleAction {
     app.set_accels_for_action("app.quit", &["<Primary>q"]);
@@ -17,6 +19,8 @@ pub fn quit_action(app: &Application) -> SimpleAction {
     let app_handle = app.clone();
 
     action.connect_activate(move |_, _| {
+        // Quitting closes windows without asking them
+        session_save(&app_handle, None);
         app_handle.quit();
     });
 
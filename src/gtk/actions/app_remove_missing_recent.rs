@@ -0,0 +1,24 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use libadwaita::Application;
+
+use crate::gtk::recent::recent_remove_missing;
+
+
+pub fn remove_missing_recent_action(app: &Application) -> SimpleAction {
+    let action = SimpleAction::new("remove-missing-recent", None);
+    let app_handle = app.clone();
+
+    action.connect_activate(move |_, _| {
+        recent_remove_missing(app_handle.upcast_ref());
+    });
+
+    action
+}
@@ -0,0 +1,30 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::Path;
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+use libadwaita::Application;
+
+use crate::gtk::recent::recent_remove;
+
+
+/// Forgets a recent file, with its path as the parameter
+pub fn remove_recent_action(app: &Application) -> SimpleAction {
+    let action = SimpleAction::new("remove-recent", Some(VariantTy::STRING));
+    let app_handle = app.clone();
+
+    action.connect_activate(move |_, parameter| {
+        if let Some(path) = parameter.and_then(|v| v.str()) {
+            recent_remove(app_handle.upcast_ref(), Path::new(path));
+        }
+    });
+
+    action
+}
@@ -9,11 +9,33 @@ pub use crate::gtk::actions::app_about::about_action;
 pub use crate::gtk::actions::app_preferences::preferences_action;
 pub use crate::gtk::actions::app_shortcuts::shortcuts_action;
 
+pub use crate::gtk::actions::app_missing_recent::missing_recent_action;
 pub use crate::gtk::actions::app_open::open_action;
+pub use crate::gtk::actions::app_open_recent::open_recent_action;
 pub use crate::gtk::actions::app_quit::quit_action;
+pub use crate::gtk::actions::app_remove_missing_recent::remove_missing_recent_action;
+pub use crate::gtk::actions::app_remove_recent::remove_recent_action;
 
+pub use crate::gtk::actions::win_check_integrity::check_integrity_action;
+pub use crate::gtk::actions::win_choose_columns::choose_columns_action;
 pub use crate::gtk::actions::win_close::close_action;
+pub use crate::gtk::actions::win_close_tab::close_tab_action;
+pub use crate::gtk::actions::win_copy_column::copy_column_action;
+pub use crate::gtk::actions::win_copy_link::copy_link_action;
 pub use crate::gtk::actions::win_copy_row::copy_row_action;
+pub use crate::gtk::actions::win_copy_row_as::copy_row_as_action;
 pub use crate::gtk::actions::win_copy_val::copy_val_action;
+pub use crate::gtk::actions::win_export::export_action;
+pub use crate::gtk::actions::win_export_database::export_database_action;
+pub use crate::gtk::actions::win_fit_column::fit_column_action;
+pub use crate::gtk::actions::win_follow::follow_action;
+pub use crate::gtk::actions::win_go_back::go_back_action;
+pub use crate::gtk::actions::win_go_forward::go_forward_action;
+pub use crate::gtk::actions::win_hide_column::hide_column_action;
+pub use crate::gtk::actions::win_jump_to_row::jump_to_row_action;
+pub use crate::gtk::actions::win_open_copy::open_copy_action;
+pub use crate::gtk::actions::win_palette::palette_action;
 pub use crate::gtk::actions::win_reload::reload_action;
+pub use crate::gtk::actions::win_retry::retry_action;
 pub use crate::gtk::actions::win_switch_table::switch_table_action;
+pub use crate::gtk::actions::win_toggle_sidebar::toggle_sidebar_action;
@@ -0,0 +1,31 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::MenuButton;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::util::widget_by_name;
+use crate::gtk::widgets::columns::WIDGET_NAME;
+
+
+/// Pops up the list of columns to show and hide
+pub fn choose_columns_action(window: &ApplicationWindow) -> SimpleAction {
+    let action = SimpleAction::new("choose-columns", None);
+    let window_handle = window.clone();
+
+    action.connect_activate(move |_, _| {
+        if let Some(button) = widget_by_name(WIDGET_NAME, window_handle.upcast_ref())
+            .and_downcast::<MenuButton>()
+        {
+            button.popup();
+        }
+    });
+
+    action
+}
@@ -12,7 +12,7 @@ use libadwaita::ApplicationWindow;
 
 pub fn close_action(window: &ApplicationWindow) -> SimpleAction {
     if let Some(app) = window.application() {
-        app.set_accels_for_action("win.close", &["<Primary>w"]);
+        app.set_accels_for_action("win.close", &["<Primary><Shift>w"]);
     }
 
     let action = SimpleAction::new("close", None);
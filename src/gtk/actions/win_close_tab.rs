@@ -0,0 +1,34 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::util::find_tab_view;
+
+
+pub fn close_tab_action(window: &ApplicationWindow) -> SimpleAction {
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.close-tab", &["<Primary>w"]);
+    }
+
+    let action = SimpleAction::new("close-tab", None);
+    let window_handle = window.clone();
+
+    // Closes the window when there are no tabs, or only one left
+    action.connect_activate(move |_, _| {
+        match find_tab_view(window_handle.upcast_ref())
+            .and_then(|tab_view| Some((tab_view.selected_page()?, tab_view)))
+        {
+            Some((page, tab_view)) => tab_view.close_page(&page),
+            None => window_handle.close(),
+        }
+    });
+
+    action
+}
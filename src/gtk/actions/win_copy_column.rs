@@ -0,0 +1,101 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+
+use libadwaita::{
+    ApplicationWindow,
+    Toast,
+    ToastOverlay,
+};
+
+use crate::bobby::prelude::*;
+use crate::bobby::export::copy::{
+    values_as_in_list,
+    values_as_lines,
+};
+
+use crate::gtk::util::{
+    copy_to_clipboard,
+    find_cache_model,
+};
+
+
+/// Copies all values of a column, with a parameter like "2:distinct-in"
+pub fn copy_column_action(
+    window: &ApplicationWindow,
+    overlay: &ToastOverlay,
+) -> SimpleAction
+{
+    let action = SimpleAction::new("copy-column", Some(VariantTy::STRING));
+
+    let window_handle = window.clone();
+    let overlay_handle = overlay.clone();
+
+    action.connect_activate(move |_, col_format| {
+        let Some((col_index, format)) = col_format
+            .and_then(|v| v.str())
+            .and_then(|s| s.split_once(':')) else {
+            return;
+        };
+
+        let (format, distinct) = match format.strip_prefix("distinct-") {
+            Some(format) => (format, true),
+            None => (format, false),
+        };
+
+        let (Ok(col_index), Ok(format)) = (
+            col_index.parse::<usize>(),
+            format.parse::<ValuesFormat>(),
+        ) else {
+            return;
+        };
+
+        let Some(model) = find_cache_model(window_handle.upcast_ref()) else {
+            return;
+        };
+
+        let (database, table) = model.database_and_table();
+
+        let copied = database
+            .columns(&table)
+            .and_then(|columns| match columns.get(col_index) {
+                Some(column) => database
+                    .column_values(&table, column, distinct)
+                    .map(|values| Some((column.name.clone(), values))),
+                None => Ok(None),
+            });
+
+        let title = match copied {
+            Ok(None) => return,
+            Ok(Some((name, values))) => {
+                let text = match format {
+                    ValuesFormat::Lines  => values_as_lines(&values),
+                    ValuesFormat::InList => values_as_in_list(&values),
+                };
+
+                _ = copy_to_clipboard(&text);
+
+                let kind = if distinct { "distinct values" } else { "values" };
+                format!("{} {kind} of ‘{name}’ copied to clipboard", values.len())
+            },
+            Err(e) => format!("Could not copy column: {e}"),
+        };
+
+        overlay_handle.dismiss_all();
+        overlay_handle.add_toast(
+            Toast::builder()
+                .title(gtk4::glib::markup_escape_text(&title))
+                .timeout(2)
+                .build()
+        );
+    });
+
+    action
+}
@@ -0,0 +1,93 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+
+use libadwaita::{
+    ApplicationWindow,
+    Toast,
+    ToastOverlay,
+};
+
+use crate::bobby::prelude::*;
+
+use crate::gtk::widgets::content::get_row;
+use crate::gtk::util::{
+    copy_to_clipboard,
+    find_cache_model,
+    find_column_view,
+};
+
+
+/// Copies a bobby:// link to a row, with a parameter like "12",
+/// or to a cell in it, with a parameter like "12:3"
+pub fn copy_link_action(
+    window: &ApplicationWindow,
+    overlay: &ToastOverlay,
+) -> SimpleAction
+{
+    let action = SimpleAction::new("copy-link", Some(VariantTy::STRING));
+
+    let window_handle = window.clone();
+    let overlay_handle = overlay.clone();
+
+    action.connect_activate(move |_, row_col_index| {
+        let Some(s) = row_col_index.and_then(|v| v.str()) else {
+            return;
+        };
+
+        let (row_index, col_index) = match s.split_once(':') {
+            Some((row, col)) => (row, col.parse::<usize>().ok()),
+            None => (s, None),
+        };
+
+        let Ok(row_index) = row_index.parse::<usize>() else {
+            return;
+        };
+
+        if let Some(column_view) = find_column_view(window_handle.upcast_ref()) &&
+           let Some(model) = find_cache_model(window_handle.upcast_ref()) &&
+           let Some(row) = get_row(column_view, row_index)
+        {
+            let (database, table) = model.database_and_table();
+
+            let column = col_index.and_then(|i| {
+                database.columns(&table).ok()?
+                    .get(i)
+                    .map(|c| c.name.clone())
+            });
+
+            let location = Location {
+                path: database.file.path().unwrap_or_default(),
+                table_name: Some(table.name()),
+                rowid: row.rowid,
+                column: column.clone(),
+            };
+
+            _ = copy_to_clipboard(&location.to_uri());
+
+            // Rows of views and WITHOUT ROWID tables can't be pointed to
+            let title = match (row.rowid, column) {
+                (None, _) => format!("Link to ‘{}’ copied to clipboard", table.name()),
+                (Some(_), Some(_)) => "Link to cell copied to clipboard".to_string(),
+                (Some(_), None) => format!("Link to row {} copied to clipboard", row_index + 1),
+            };
+
+            overlay_handle.dismiss_all();
+            overlay_handle.add_toast(
+                Toast::builder()
+                    .title(gtk4::glib::markup_escape_text(&title))
+                    .timeout(2)
+                    .build()
+            );
+        }
+    });
+
+    action
+}
@@ -8,9 +8,6 @@
 use gio::SimpleAction;
 
 use gtk4::prelude::*;
-use gtk4::{
-    SingleSelection,
-};
 
 use libadwaita::{
     ApplicationWindow,
@@ -19,14 +16,22 @@ use libadwaita::{
 };
 
 use crate::bobby::prelude::*;
+use crate::bobby::session::position_runs;
+use crate::log;
 
-use crate::gtk::widgets::content::get_row;
+use crate::gtk::widgets::range::{ cell_range, column_order };
 use crate::gtk::util::{
     copy_to_clipboard,
+    find_cache_model,
     find_column_view,
+    selected_rows_limit,
 };
 
 
+/// Copying more would make the clipboard slow to paste from
+pub const MAX_COPY_ROWS: usize = 100_000;
+
+
 pub fn copy_row_action(
     window: &ApplicationWindow,
     overlay: &ToastOverlay,
@@ -43,28 +48,72 @@ pub fn copy_row_action(
 
     action.connect_activate(move |_, _| {
         if let Some(column_view) = find_column_view(window_handle.upcast_ref()) &&
-           let Some(model) = column_view.model() &&
-           let Some(selection) = model.downcast::<SingleSelection>().ok()
+           let Some(model) = find_cache_model(window_handle.upcast_ref())
         {
-            let row_index = selection.selected() as usize;
-
-            if let Some(row) = get_row(column_view, row_index) {
-                let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
-                let separator = settings.string("column-separator");
-                let separator = separator.as_str().parse::<ColumnSeparator>();
-
-                _ = copy_to_clipboard(
-                    &row.format_with(separator.unwrap_or_default())
-                );
-
-                overlay_handle.dismiss_all();
-                overlay_handle.add_toast(
-                    Toast::builder()
-                        .title(format!("Row {} copied to clipboard", row_index + 1))
-                        .timeout(2)
-                        .build()
-                );
+            let range = cell_range(&column_view);
+
+            let (positions, n_selected) = match &range {
+                Some(range) => (range.rows().take(MAX_COPY_ROWS).collect(), range.rows().count() as u64),
+                None => selected_rows_limit(&column_view, MAX_COPY_ROWS),
+            };
+
+            let (database, table) = model.database_and_table();
+
+            // Straight from the file, as large selections would go through the cache a page at a time
+            let rows = match database.rows_at(&table, &position_runs(&positions)) {
+                Ok(rows) => rows,
+                Err(e) => {
+                    log::error(&e.to_string());
+                    return;
+                },
+            };
+
+            if rows.is_empty() {
+                return;
             }
+
+            let columns = database.columns(&table).unwrap_or_default();
+
+            let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
+            let separator = settings.string("column-separator");
+            let separator = separator.as_str().parse::<ColumnSeparator>();
+
+            let separator = separator.unwrap_or_default();
+
+            // Just the block of cells, when there is one
+            let (text, title) = match range {
+                Some(range) => {
+                    let order = column_order(&column_view);
+                    let indices = range.columns().filter_map(|i| order.get(i).copied()).collect::<Vec<_>>();
+
+                    (
+                        Row::format_cells_with(&rows, &columns, &indices, separator),
+                        format!("{} × {} cells copied to clipboard", rows.len(), indices.len()),
+                    )
+                },
+                None => (
+                    Row::format_rows_with(&rows, &columns, separator),
+                    match positions.as_slice() {
+                        [position] => format!("Row {} copied to clipboard", position + 1),
+                        _ => format!("{} rows copied to clipboard", rows.len()),
+                    },
+                ),
+            };
+
+            let title = match n_selected > rows.len() as u64 {
+                true => format!("Only the first {} of {n_selected} rows copied to clipboard", rows.len()),
+                false => title,
+            };
+
+            _ = copy_to_clipboard(&text);
+
+            overlay_handle.dismiss_all();
+            overlay_handle.add_toast(
+                Toast::builder()
+                    .title(title)
+                    .timeout(2)
+                    .build()
+            );
         }
     });
 
@@ -0,0 +1,80 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+
+use libadwaita::{
+    ApplicationWindow,
+    Toast,
+    ToastOverlay,
+};
+
+use crate::bobby::prelude::*;
+use crate::bobby::session::position_runs;
+
+use crate::gtk::actions::win_copy_row::MAX_COPY_ROWS;
+use crate::gtk::util::{
+    copy_to_clipboard,
+    find_cache_model,
+    find_column_view,
+    selected_rows_limit,
+};
+
+
+pub fn copy_row_as_action(
+    window: &ApplicationWindow,
+    overlay: &ToastOverlay,
+) -> SimpleAction
+{
+    let action = SimpleAction::new("copy-row-as", Some(VariantTy::STRING));
+
+    let window_handle = window.clone();
+    let overlay_handle = overlay.clone();
+
+    action.connect_activate(move |_, format| {
+        let Some(format) = format
+            .and_then(|v| v.str())
+            .and_then(|s| s.parse::<CopyFormat>().ok()) else {
+            return;
+        };
+
+        if let Some(column_view) = find_column_view(window_handle.upcast_ref()) &&
+           let Some(model) = find_cache_model(window_handle.upcast_ref())
+        {
+            let (positions, _) = selected_rows_limit(&column_view, MAX_COPY_ROWS);
+            let (database, table) = model.database_and_table();
+
+            // One line per selected row
+            let copied = database.copy_rows_as(&table, &position_runs(&positions), format);
+
+            let title = match (copied, positions.as_slice()) {
+                (Ok(lines), _) if lines.is_empty() => return,
+                (Ok(lines), [position]) => {
+                    _ = copy_to_clipboard(&lines.join("\n"));
+                    format!("Row {} copied as {}", position + 1, format.name())
+                },
+                (Ok(lines), _) => {
+                    _ = copy_to_clipboard(&lines.join("\n"));
+                    format!("{} rows copied as {}", lines.len(), format.name())
+                },
+                (Err(e), _) => format!("Could not copy row: {e}"),
+            };
+
+            overlay_handle.dismiss_all();
+            overlay_handle.add_toast(
+                Toast::builder()
+                    .title(gtk4::glib::markup_escape_text(&title))
+                    .timeout(2)
+                    .build()
+            );
+        }
+    });
+
+    action
+}
@@ -0,0 +1,48 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+
+use libadwaita::{
+    ApplicationWindow,
+    ToastOverlay,
+};
+
+use crate::gtk::dialogs::export::show_export_dialog;
+use crate::gtk::util::find_cache_model;
+
+
+pub fn export_database_action(
+    window: &ApplicationWindow,
+    overlay: &ToastOverlay,
+) -> SimpleAction
+{
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.export-database", &["<Primary><Shift>e"]);
+    }
+
+    let action = SimpleAction::new("export-database", None);
+
+    let window_handle = window.clone();
+    let overlay_handle = overlay.clone();
+
+    action.connect_activate(move |_, _| {
+        if let Some(model) = find_cache_model(window_handle.upcast_ref()) {
+            let (database, _) = model.database_and_table();
+
+            show_export_dialog(
+                window_handle.upcast_ref(),
+                &database,
+                None,
+                &overlay_handle,
+            );
+        }
+    });
+
+    action
+}
@@ -0,0 +1,46 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::util::{ find_cache_model, find_column_view };
+use crate::gtk::view::view_column;
+use crate::gtk::widgets::columns::column_fit_width;
+
+
+/// Sizes a column to fit its title and the rows loaded so far,
+/// with its index as the parameter, like "2"
+pub fn fit_column_action(window: &ApplicationWindow) -> SimpleAction {
+    let action = SimpleAction::new("fit-column", Some(VariantTy::STRING));
+    let window_handle = window.clone();
+
+    action.connect_activate(move |_, col_index| {
+        let Some(col_index) = col_index
+            .and_then(|v| v.str())
+            .and_then(|s| s.parse::<usize>().ok()) else {
+            return;
+        };
+
+        let (Some(column_view), Some(model)) = (
+            find_column_view(window_handle.upcast_ref()),
+            find_cache_model(window_handle.upcast_ref()),
+        ) else {
+            return;
+        };
+
+        // Views have the row numbers first
+        if let Some(column) = view_column(&column_view, col_index + 1) {
+            let width = column_fit_width(&column_view, &column, &model.cached_texts(col_index));
+            column.set_fixed_width(width);
+        }
+    });
+
+    action
+}
@@ -0,0 +1,123 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use gio::SimpleAction;
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    glib::ControlFlow,
+    glib::SourceId,
+    glib::Variant,
+};
+
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::util::{ find_cache_model, find_column_view };
+
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+pub fn follow_action(window: &ApplicationWindow) -> SimpleAction {
+    let action = SimpleAction::new_stateful(
+        "follow",
+        None,
+        &Variant::from(false),
+    );
+
+    let window_weak = window.downgrade();
+
+    // The one timer polling for new rows, while following
+    let timer: Rc<RefCell<Option<SourceId>>> = Rc::default();
+
+    action.connect_change_state(move |action, value| {
+        let Some(following) = value.and_then(|v| v.get::<bool>()) else {
+            return;
+        };
+
+        action.set_state(&Variant::from(following));
+
+        if !following {
+            if let Some(source) = timer.take() {
+                source.remove();
+            }
+
+            return;
+        }
+
+        if timer.borrow().is_some() {
+            return;
+        }
+
+        let window_weak = window_weak.clone();
+        let timer_handle = timer.clone();
+
+        let source = glib::timeout_add_local(POLL_INTERVAL, move || {
+            match window_weak.upgrade() {
+                Some(window) => {
+                    follow_tick(&window);
+                    ControlFlow::Continue
+                },
+                None => {
+                    // Removed by returning Break, so not to be removed again
+                    timer_handle.take();
+                    ControlFlow::Break
+                },
+            }
+        });
+
+        timer.replace(Some(source));
+    });
+
+    action
+}
+
+
+/// Whether the window is following new rows, and
+/// shouldn't be interrupted by file change notices
+pub fn is_following(window: &ApplicationWindow) -> bool {
+    window
+        .lookup_action("follow")
+        .and_then(|action| action.state())
+        .and_then(|state| state.get::<bool>())
+        .unwrap_or(false)
+}
+
+
+fn follow_tick(window: &ApplicationWindow) {
+    // Look it up every time, as the table may have been switched
+    let (Some(column_view), Some(model)) = (
+        find_column_view(window.upcast_ref()),
+        find_cache_model(window.upcast_ref()),
+    ) else {
+        return;
+    };
+
+    let added = match model.refresh() {
+        Ok(added) => added,
+        Err(e) => {
+            eprintln!("Could not follow new rows: {e}");
+            return;
+        },
+    };
+
+    if added == 0 {
+        return;
+    }
+
+    // Stay pinned to the newest rows
+    let newest = match model.is_newest_first() {
+        true => 0,
+        false => model.n_items().saturating_sub(1),
+    };
+
+    column_view.scroll_to(newest, None, gtk4::ListScrollFlags::NONE, None);
+}
@@ -0,0 +1,33 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::windows::history::window_go_back;
+use crate::log;
+
+
+pub fn go_back_action(window: &ApplicationWindow) -> SimpleAction {
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.back", &["<Alt>Left", "Back"]);
+    }
+
+    let action = SimpleAction::new("back", None);
+    action.set_enabled(false); // Nowhere to go yet
+
+    let window_handle = window.clone();
+
+    action.connect_activate(move |_, _| {
+        if let Err(e) = window_go_back(window_handle.upcast_ref()) {
+            log::error(&e.to_string());
+        }
+    });
+
+    action
+}
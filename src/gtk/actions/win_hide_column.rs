@@ -0,0 +1,46 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::util::find_column_view;
+use crate::gtk::view::view_column;
+use crate::gtk::widgets::range::column_order;
+
+
+/// Hides a column, with its index as the parameter, like "2"
+pub fn hide_column_action(window: &ApplicationWindow) -> SimpleAction {
+    let action = SimpleAction::new("hide-column", Some(VariantTy::STRING));
+    let window_handle = window.clone();
+
+    action.connect_activate(move |_, col_index| {
+        let Some(col_index) = col_index
+            .and_then(|v| v.str())
+            .and_then(|s| s.parse::<usize>().ok()) else {
+            return;
+        };
+
+        let Some(column_view) = find_column_view(window_handle.upcast_ref()) else {
+            return;
+        };
+
+        // Keep at least one column to bring the others back from
+        if column_order(&column_view).len() <= 1 {
+            return;
+        }
+
+        // Views have the row numbers first
+        if let Some(column) = view_column(&column_view, col_index + 1) {
+            column.set_visible(false);
+        }
+    });
+
+    action
+}
@@ -0,0 +1,73 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use gtk4::glib::VariantTy;
+use gtk4::MenuButton;
+
+use libadwaita::{
+    ApplicationWindow,
+    Toast,
+    ToastOverlay,
+};
+
+use crate::bobby::prelude::*;
+use crate::gtk::util::widget_by_name;
+use crate::gtk::windows::prelude::*;
+
+
+/// Scrolls to a row, with a parameter like "number:12", "rowid:1234" or "key:ABC-1"
+pub fn jump_to_row_action(
+    window: &ApplicationWindow,
+    overlay: &ToastOverlay,
+) -> SimpleAction
+{
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.jump-to-row::", &["<Primary>l"]);
+    }
+
+    let action = SimpleAction::new("jump-to-row", Some(VariantTy::STRING));
+
+    let window_handle = window.clone();
+    let overlay_handle = overlay.clone();
+
+    action.connect_activate(move |_, target| {
+        let Some(target) = target.and_then(|v| v.str()) else {
+            return;
+        };
+
+        // Without a row, ask for one
+        if target.is_empty() {
+            if let Some(button) = widget_by_name("jump", window_handle.upcast_ref())
+                .and_downcast::<MenuButton>()
+            {
+                button.popup();
+            }
+
+            return;
+        }
+
+        let (kind, text) = target.split_once(':').unwrap_or(("number", target));
+
+        let result = RowTarget::new(kind, text)
+            .map_err(|e| e.into())
+            .and_then(|target| window_jump_to_row(window_handle.upcast_ref(), &target));
+
+        if let Err(e) = result {
+            overlay_handle.dismiss_all();
+            overlay_handle.add_toast(
+                Toast::builder()
+                    .title(gtk4::glib::markup_escape_text(&e.to_string()))
+                    .timeout(2)
+                    .build()
+            );
+        }
+    });
+
+    action
+}
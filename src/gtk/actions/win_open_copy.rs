@@ -0,0 +1,38 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::{
+    File,
+    SimpleAction,
+};
+
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+use crate::bobby::prelude::*;
+use crate::gtk::cache::cache_copies;
+use crate::gtk::windows::window::window_retry;
+
+
+pub fn open_copy_action(window: &ApplicationWindow, file: &File) -> SimpleAction {
+    let action = SimpleAction::new("open-copy", None);
+
+    let window_handle = window.clone();
+    let file_handle = file.clone();
+
+    action.connect_activate(move |_, _| {
+        let result = Database::copy_to(&file_handle, &cache_copies())
+            .map_err(|e| e.into())
+            .and_then(|copy| window_retry(window_handle.upcast_ref(), &copy));
+
+        if let Err(e) = result {
+            eprintln!("Could not open a copy: {e}");
+        }
+    });
+
+    action
+}
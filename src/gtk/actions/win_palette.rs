@@ -0,0 +1,28 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::SimpleAction;
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::dialogs::palette::show_palette_dialog;
+
+
+pub fn palette_action(window: &ApplicationWindow) -> SimpleAction {
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.palette", &["<Primary>k", "<Primary>p"]);
+    }
+
+    let action = SimpleAction::new("palette", None);
+    let window_handle = window.clone();
+
+    action.connect_activate(move |_, _| {
+        show_palette_dialog(&window_handle);
+    });
+
+    action
+}
@@ -0,0 +1,32 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::{
+    File,
+    SimpleAction,
+};
+
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+use crate::gtk::windows::window::window_retry;
+
+
+pub fn retry_action(window: &ApplicationWindow, file: &File) -> SimpleAction {
+    let action = SimpleAction::new("retry", None);
+
+    let window_handle = window.clone();
+    let file_handle = file.clone();
+
+    action.connect_activate(move |_, _| {
+        if let Err(e) = window_retry(window_handle.upcast_ref(), &file_handle) {
+            eprintln!("Could not retry opening file: {e}");
+        }
+    });
+
+    action
+}
@@ -5,6 +5,9 @@
 //   under the terms of the GNU General Public License v3 or any later version.
 
 
+use std::cell::RefCell;
+use std::path::Path;
+
 use gio::SimpleAction;
 
 use gtk4::prelude::*;
@@ -13,15 +16,19 @@ use gtk4::{
     MenuButton,
 };
 
-use libadwaita::ApplicationWindow;
+use libadwaita::{ ApplicationWindow, TabView };
 
 use crate::bobby::prelude::*;
+use crate::gtk::recent::recent_set_table;
+use crate::gtk::widgets::tabs::{ tab_page_add, tab_page_find, tab_pages };
+use crate::gtk::windows::history::{ tab_place, window_history_visit };
 use crate::gtk::windows::window::window_change_content;
 
 
+/// Selects the tab of the table, opening one if needed
 pub fn switch_table_action(
     window: &ApplicationWindow,
-    layout: gtk4::Box,
+    tab_view: TabView,
     table_index: String,
     tables: Vec<Table>,
     switcher: MenuButton,
@@ -33,31 +40,69 @@ pub fn switch_table_action(
         &Variant::from(table_index),
     );
 
-    let window_handle = window.clone();
-    let layout_handle = layout.clone();
-    let switcher_handle = switcher.clone();
+    let table_names = tables
+        .iter()
+        .map(|table| table.name())
+        .collect::<Vec<_>>();
 
-    action.connect_change_state(move |action, value| {
-        if let Some(v) = value {
-            action.set_state(v);
-        }
+    let window_handle = window.clone();
+    let tab_view_handle = tab_view.clone();
 
-        if let Some(table) = value
+    action.connect_change_state(move |_, value| {
+        let Some(table) = value
             .and_then(|v| v.str())
             .and_then(|s| s.parse::<usize>().ok())
             .and_then(|i| tables.get(i))
+        else {
+            return;
+        };
+
+        if let Some(page) = tab_page_find(&tab_view_handle, &table.name()) {
+            tab_view_handle.set_selected_page(&page);
+            return;
+        }
+
+        match window_change_content(&window_handle, table) {
+            Ok(content) => {
+                let page = tab_page_add(&tab_view_handle, table, &content);
+                tab_view_handle.set_selected_page(&page);
+            },
+            Err(e) => eprintln!("Could not change content: {e}"),
+        };
+    });
+
+    let action_weak = action.downgrade();
+    let window_weak = window.downgrade();
+    let previous = RefCell::new(tab_view.selected_page());
+
+    // Keep the switcher and the menu in step with the selected tab
+    tab_view.connect_selected_page_notify(move |tab_view| {
+        let Some(page) = tab_view.selected_page() else {
+            return;
+        };
+
+        // Closed tabs aren't somewhere to go back to
+        if let Some(previous) = previous.replace(Some(page.clone())) &&
+           tab_pages(tab_view).contains(&previous) &&
+           let Some(place) = tab_place(&previous) &&
+           let Some(window) = window_weak.upgrade()
+        {
+            window_history_visit(window.upcast_ref(), place);
+        }
+
+        let title = page.title();
+        switcher.set_label(&title);
+
+        if let Some(window) = window_weak.upgrade() &&
+           let Some(app) = window.application()
+        {
+            recent_set_table(&app, Path::new(&window.widget_name()), &title);
+        }
+
+        if let Some(action) = action_weak.upgrade() &&
+           let Some(index) = table_names.iter().position(|name| *name == title)
         {
-            switcher_handle.set_label(&table.name());
-
-            match window_change_content(&window_handle, table) {
-                Ok(new_content) => {
-                    if let Some(old_content) = layout_handle.last_child() {
-                        layout_handle.remove(&old_content);
-                        layout_handle.append(&new_content);
-                    }
-                },
-                Err(e) => eprintln!("Could not change content: {e}"),
-            };
+            action.set_state(&Variant::from(index.to_string()));
         }
     });
 
@@ -0,0 +1,21 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::prelude::*;
+use gtk4::prelude::*;
+use libadwaita::ApplicationWindow;
+
+
+/// Toggles the "sidebar" setting, which every window follows
+pub fn toggle_sidebar_action(window: &ApplicationWindow) -> gio::Action {
+    if let Some(app) = window.application() {
+        app.set_accels_for_action("win.sidebar", &["F9"]);
+    }
+
+    let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
+    settings.create_action("sidebar")
+}
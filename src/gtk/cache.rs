@@ -0,0 +1,64 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::env;
+use std::fs;
+use std::path::{ Path, PathBuf };
+
+use gtk4::prelude::*;
+use gtk4::Application;
+
+use crate::bobby::sqlite::database::SNAPSHOTS_DIR;
+
+
+const COPIES_DIR: &str = "copies";
+
+
+/// Keeps copies of databases in `app_cache_home`
+pub fn cache_init(app: &Application, app_cache_home: &Path) {
+    // SAFETY: Only ever set as PathBuf
+    unsafe {
+        app.set_data("cache", app_cache_home.to_path_buf());
+    }
+}
+
+
+fn cache_home() -> PathBuf {
+    let app = gio::Application::default();
+
+    // SAFETY: Read back as PathBuf, set in cache_init()
+    let path = app.and_then(|app| unsafe {
+        app.data::<PathBuf>("cache")
+            .map(|path| path.as_ref().clone())
+    });
+
+    path.unwrap_or_else(env::temp_dir)
+}
+
+
+/// Where files that can't be read in place are copied to
+pub fn cache_snapshots() -> PathBuf {
+    cache_home().join(SNAPSHOTS_DIR)
+}
+
+
+/// Where "Open a Copy" puts its copies
+pub fn cache_copies() -> PathBuf {
+    cache_home().join(COPIES_DIR)
+}
+
+
+/// Whether `path` is one of our copies, which won't be around next time
+pub fn cache_contains(path: &Path) -> bool {
+    path.starts_with(cache_home())
+}
+
+
+/// Removes the copies, as no window has them open anymore when quitting
+pub fn cache_clear_copies() {
+    _ = fs::remove_dir_all(cache_copies());
+}
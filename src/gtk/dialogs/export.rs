@@ -0,0 +1,199 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::fs;
+use std::io;
+use std::io::{ BufWriter, Write };
+use std::path::Path;
+
+use gio::{
+    Cancellable,
+    File,
+    ListStore,
+    Settings,
+};
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    FileDialog,
+    FileFilter,
+    Window,
+};
+
+use libadwaita::{
+    Toast,
+    ToastOverlay,
+};
+
+use crate::bobby::prelude::*;
+use crate::bobby::Error;
+use crate::gtk::cache::cache_snapshots;
+
+
+/// Exports `table`, or the whole database when `None`
+pub fn show_export_dialog(
+    parent: &Window,
+    database: &Database,
+    table: Option<&Table>,
+    overlay: &ToastOverlay,
+) {
+    let formats = ExportFormat::ALL
+        .into_iter()
+        .filter(|format| table.is_some() || format.supports_database())
+        .collect::<Vec<_>>();
+
+    let default_format = formats.first().copied().unwrap_or_default();
+
+    let (title, name) = match table {
+        Some(table) => ("Export Table", table.name()),
+        None => ("Export Database", database_name(database)),
+    };
+
+    let dialog = FileDialog::builder()
+        .title(title)
+        .initial_name(format!("{name}.{}", default_format.extension()))
+        .filters(&filters(&formats))
+        .modal(true)
+        .build();
+
+    let file = database.file.clone();
+    let row_order = database.row_order;
+    let table = table.cloned();
+    let overlay = overlay.clone();
+
+    dialog.save(
+        Some(parent),
+        None::<&Cancellable>,
+        move |result| {
+            // Cancelled
+            let Ok(destination) = result else {
+                return;
+            };
+
+            export_in_background(file, row_order, table, destination, overlay, formats);
+        },
+    );
+}
+
+
+fn database_name(database: &Database) -> String {
+    database.file
+        .path()
+        .and_then(|path| path.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+        .unwrap_or("database".into())
+}
+
+
+fn filters(formats: &[ExportFormat]) -> ListStore {
+    let filters = ListStore::new::<FileFilter>();
+
+    for format in formats {
+        let filter = FileFilter::new();
+        filter.set_name(Some(format.name()));
+        filter.add_mime_type(format.mime_type());
+        filter.add_suffix(format.extension());
+        filters.append(&filter);
+    }
+
+    filters
+}
+
+
+fn export_in_background(
+    file: File,
+    row_order: Option<RowOrder>,
+    table: Option<Table>,
+    destination: File,
+    overlay: ToastOverlay,
+    formats: Vec<ExportFormat>,
+) {
+    let Some(path) = destination.path() else {
+        return;
+    };
+
+    let format = ExportFormat::from_path(&path)
+        .filter(|format| formats.contains(format))
+        .or(formats.first().copied())
+        .unwrap_or_default();
+    let options = CsvOptions::from_settings();
+
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let snapshots = cache_snapshots();
+
+    // Own connection, as the one in the window can't be shared across threads
+    let handle = gio::spawn_blocking(move || {
+        let database = Database::from_file(&file, row_order, &snapshots)?;
+        export_to_path(&database, table.as_ref(), format, &path, &options)
+    });
+
+    glib::MainContext::default().spawn_local(async move {
+        let title = match handle.await {
+            Ok(Ok(count)) => format!("Exported {count} rows to ‘{file_name}’"),
+            Ok(Err(e)) => format!("Could not export: {e}"),
+            Err(_) => "Could not export".to_string(),
+        };
+
+        overlay.add_toast(
+            Toast::builder()
+                .title(glib::markup_escape_text(&title))
+                .timeout(4)
+                .build()
+        );
+    });
+}
+
+
+fn export_to_path(
+    database: &Database,
+    table: Option<&Table>,
+    format: ExportFormat,
+    path: &Path,
+    options: &CsvOptions,
+) -> Result<u64, Error>
+{
+    let mut writer = BufWriter::new(fs::File::create(path)?);
+
+    let count = match (format, table) {
+        (ExportFormat::Csv, Some(table))    => database.export_csv(table, &mut writer, options, None)?,
+        (ExportFormat::Json, Some(table))   => database.export_json(table, &mut writer, None)?,
+        (ExportFormat::Ndjson, Some(table)) => database.export_ndjson(table, &mut writer)?,
+        (ExportFormat::Sql, table)          => database.export_sql(table, &mut writer)?,
+        (ExportFormat::Ods, table)          => database.export_ods(table, &mut writer, options.blob)?,
+
+        (format, None) => return Err(Error::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} can only hold a single table", format.name()),
+        ))),
+    };
+
+    writer.flush()?;
+    Ok(count)
+}
+
+
+impl CsvOptions {
+    pub fn from_settings() -> Self {
+        let settings = Settings::new("studio.planetpeanut.Bobby"); // TODO
+
+        let delimiter = match settings.string("export-delimiter").as_str() {
+            "semicolons" => ';',
+            "tabs"       => '\t',
+            _            => ',',
+        };
+
+        Self {
+            delimiter,
+            null: settings.string("export-null").parse().unwrap_or_default(),
+            blob: settings.string("export-blob").parse().unwrap_or_default(),
+        }
+    }
+}
@@ -20,6 +20,7 @@ use gtk4::{
     Window,
 };
 
+use crate::bobby::location::Location;
 use crate::gtk::windows::window::window_handle_open;
 
 
@@ -92,7 +93,7 @@ fn handle_files(
             .and_then(|obj| obj.downcast::<File>().ok())
             .ok_or("ListModel item is not a gio::File")?;
 
-        window_handle_open(&application, &file, None)?;
+        window_handle_open(&application, &file, &Location::default())?;
     }
 
     Ok(())
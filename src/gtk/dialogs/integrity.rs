@@ -0,0 +1,45 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::File;
+use gtk4::Window;
+
+use libadwaita::prelude::*;
+use libadwaita::AlertDialog;
+
+use crate::bobby::prelude::*;
+
+
+const MAX_MESSAGES: usize = 8;
+
+pub fn show_integrity_dialog(parent: &Window, file: &File) {
+    let (heading, body) = match Database::integrity_check(file) {
+        Ok(messages) if messages == ["ok"] => (
+            "No Problems Found",
+            "The file passed SQLite’s integrity check".to_string(),
+        ),
+        Ok(messages) => {
+            let mut body = messages
+                .iter()
+                .take(MAX_MESSAGES)
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            if messages.len() > MAX_MESSAGES {
+                body.push_str(&format!("\n… and {} more", messages.len() - MAX_MESSAGES));
+            }
+
+            ("Problems Found", body)
+        },
+        Err(e) => ("Unable to Check File", e.to_string()),
+    };
+
+    let dialog = AlertDialog::new(Some(heading), Some(&body));
+    dialog.add_response("close", "Close");
+    dialog.present(Some(parent));
+}
@@ -0,0 +1,354 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::{
+    gdk::Key,
+    glib::Propagation,
+    glib::Variant,
+    EventControllerKey,
+    ListBox,
+    ScrolledWindow,
+    SearchEntry,
+    SelectionMode,
+};
+
+use libadwaita::prelude::*;
+use libadwaita::{
+    ActionRow,
+    ApplicationWindow,
+    Dialog,
+    HeaderBar,
+    ToolbarView,
+};
+
+use crate::bobby::fuzzy::fuzzy_score;
+use crate::bobby::prelude::*;
+use crate::gtk::recent::{ recent_file_title, recent_files };
+use crate::gtk::windows::prelude::*;
+use crate::log;
+
+
+const MAX_RESULTS: usize = 50;
+
+
+/// What choosing an entry does
+enum PaletteTarget {
+    Action(String, Option<Variant>),
+    Location(Location),
+}
+
+struct PaletteItem {
+    title: String,
+    subtitle: String,
+    target: PaletteTarget,
+}
+
+
+/// Finds tables, columns, recent files and actions by typing part of their name
+pub fn show_palette_dialog(window: &ApplicationWindow) {
+    let items = Rc::new(palette_items(window));
+    let shown: Rc<RefCell<Vec<usize>>> = Rc::default();
+
+    let search_entry = SearchEntry::builder()
+        .placeholder_text("Tables, columns, files and actions")
+        .hexpand(true)
+        .build();
+
+    let list = ListBox::builder()
+        .selection_mode(SelectionMode::Browse)
+        .css_classes(["navigation-sidebar"])
+        .build();
+
+    let scrolled_window = ScrolledWindow::builder()
+        .hscrollbar_policy(gtk4::PolicyType::Never)
+        .vexpand(true)
+        .child(&list)
+        .build();
+
+    let header = HeaderBar::builder()
+        .title_widget(&search_entry)
+        .build();
+
+    let toolbar_view = ToolbarView::new();
+    toolbar_view.add_top_bar(&header);
+    toolbar_view.set_content(Some(&scrolled_window));
+
+    let dialog = Dialog::builder()
+        .title("Command Palette")
+        .content_width(520)
+        .content_height(440)
+        .child(&toolbar_view)
+        .focus_widget(&search_entry)
+        .build();
+
+    palette_update(&list, &items, &shown, "");
+
+
+    let list_handle = list.clone();
+    let items_handle = items.clone();
+    let shown_handle = shown.clone();
+
+    search_entry.connect_search_changed(move |entry| {
+        palette_update(&list_handle, &items_handle, &shown_handle, &entry.text());
+    });
+
+    let list_handle = list.clone();
+
+    search_entry.connect_activate(move |_| {
+        if let Some(row) = list_handle.selected_row() {
+            row.emit_activate();
+        }
+    });
+
+    // Move through the results without leaving the search entry
+    let keys = EventControllerKey::new();
+    let list_handle = list.clone();
+    let scrolled_window_handle = scrolled_window.clone();
+
+    keys.connect_key_pressed(move |_, key, _, _| {
+        let step = match key {
+            Key::Up   => -1,
+            Key::Down => 1,
+            _ => return Propagation::Proceed,
+        };
+
+        let index = list_handle
+            .selected_row()
+            .map(|row| row.index() + step)
+            .unwrap_or(0);
+
+        if let Some(row) = list_handle.row_at_index(index) {
+            list_handle.select_row(Some(&row));
+
+            if let Some(bounds) = row.compute_bounds(&list_handle) {
+                scrolled_window_handle.vadjustment().clamp_page(
+                    bounds.y() as f64,
+                    (bounds.y() + bounds.height()) as f64,
+                );
+            }
+        }
+
+        Propagation::Stop
+    });
+
+    search_entry.add_controller(keys);
+
+
+    let window_handle = window.clone();
+    let dialog_handle = dialog.clone();
+
+    list.connect_row_activated(move |_, row| {
+        let Some(item) = shown
+            .borrow()
+            .get(row.index() as usize)
+            .and_then(|&i| items.get(i))
+        else {
+            return;
+        };
+
+        dialog_handle.close();
+        palette_run(&window_handle, &item.target);
+    });
+
+    dialog.present(Some(window));
+}
+
+
+/// Shows the best matches for `query`, or everything in order when it's empty
+fn palette_update(
+    list: &ListBox,
+    items: &[PaletteItem],
+    shown: &RefCell<Vec<usize>>,
+    query: &str,
+) {
+    let mut matches = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| Some((i, fuzzy_score(query, &item.title)?)))
+        .collect::<Vec<_>>();
+
+    matches.sort_by_key(|(_, score)| -score); // Stable, so ties keep their kind order
+    matches.truncate(MAX_RESULTS);
+
+    list.remove_all();
+
+    for (i, _) in &matches {
+        let row = ActionRow::builder()
+            .title(&items[*i].title)
+            .subtitle(&items[*i].subtitle)
+            .use_markup(false)
+            .build();
+
+        list.append(&row);
+    }
+
+    list.select_row(list.row_at_index(0).as_ref());
+    shown.replace(matches.into_iter().map(|(i, _)| i).collect());
+}
+
+
+fn palette_run(window: &ApplicationWindow, target: &PaletteTarget) {
+    match target {
+        PaletteTarget::Action(name, parameter) => {
+            if let Err(e) = WidgetExt::activate_action(window, name, parameter.as_ref()) {
+                log::error(&e.to_string());
+            }
+        },
+        PaletteTarget::Location(location) => {
+            if let Err(e) = window_show_location(window.upcast_ref(), location) {
+                log::error(&e.to_string());
+            }
+        },
+    }
+}
+
+
+fn palette_items(window: &ApplicationWindow) -> Vec<PaletteItem> {
+    let mut items = Vec::new();
+
+    // SAFETY: Window outlives the database
+    let database = unsafe {
+        window
+            .data::<Database>("db")
+            .map(|db| db.as_ref().clone())
+    };
+
+    if let Some(database) = &database &&
+       let Err(e) = palette_database_items(database, &mut items)
+    {
+        log::error(&e.to_string());
+    }
+
+    let current = database.and_then(|db| db.file.path());
+
+    let recent = window
+        .application()
+        .map(|app| recent_files(&app))
+        .unwrap_or_default();
+
+    for file in recent.files() {
+        if !file.exists() || Some(&file.path) == current.as_ref() {
+            continue;
+        }
+
+        items.push(PaletteItem {
+            title: recent_file_title(file),
+            subtitle: format!("Recent File · {}", file.path.display()),
+            target: PaletteTarget::Action(
+                "app.open-recent".into(),
+                Some(file.path.to_string_lossy().to_variant()),
+            ),
+        });
+    }
+
+    palette_action_items(window, &mut items);
+
+    items
+}
+
+
+fn palette_database_items(
+    database: &Database,
+    items: &mut Vec<PaletteItem>,
+) -> Result<(), Box<dyn std::error::Error>>
+{
+    let path = database.file.path().ok_or("Missing file")?;
+    let tables = database.tables()?;
+
+    for (i, table) in tables.iter().enumerate() {
+        let kind = match (table.is_view(), table.is_virtual()) {
+            (true, _) => "View",
+            (_, true) => "Virtual Table",
+            _ => "Table",
+        };
+
+        items.push(PaletteItem {
+            title: table.name(),
+            subtitle: kind.to_string(),
+            target: PaletteTarget::Action("win.table".into(), Some(i.to_string().to_variant())),
+        });
+    }
+
+    for table in &tables {
+        for column in database.columns(table)? {
+            items.push(PaletteItem {
+                subtitle: format!("Column in {}", table.name()),
+                target: PaletteTarget::Location(Location {
+                    table_name: Some(table.name()),
+                    column: Some(column.name.clone()),
+                    ..Location::new(&path)
+                }),
+                title: column.name,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+
+/// Every action that can run without a parameter, with its shortcut
+fn palette_action_items(window: &ApplicationWindow, items: &mut Vec<PaletteItem>) {
+    let Some(app) = window.application() else {
+        return;
+    };
+
+    let groups: [(&str, &gio::ActionGroup, &gio::ActionMap); 2] = [
+        ("win", window.upcast_ref(), window.upcast_ref()),
+        ("app", app.upcast_ref(), app.upcast_ref()),
+    ];
+
+    for (prefix, group, map) in groups {
+        let mut names = group.list_actions();
+        names.sort();
+
+        for name in names {
+            let Some(action) = map.lookup_action(&name) else {
+                continue;
+            };
+
+            if action.parameter_type().is_some() || !action.is_enabled() || name == "palette" {
+                continue;
+            }
+
+            let detailed_name = format!("{prefix}.{name}");
+
+            let shortcut = app
+                .accels_for_action(&detailed_name)
+                .first()
+                .and_then(gtk4::accelerator_parse)
+                .map(|(key, modifiers)| format!(" · {}", gtk4::accelerator_get_label(key, modifiers)))
+                .unwrap_or_default();
+
+            items.push(PaletteItem {
+                title: action_title(&name),
+                subtitle: format!("Action{shortcut}"),
+                target: PaletteTarget::Action(detailed_name, None),
+            });
+        }
+    }
+}
+
+
+/// "export-database" becomes "Export Database"
+fn action_title(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
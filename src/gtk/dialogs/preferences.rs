@@ -44,6 +44,22 @@ pub fn show_preferences_dialog(parent: &Window) {
     group_rows_columns.add(&row_separator(&settings));
 
 
+    let group_files = PreferencesGroup::builder()
+        .title("Files")
+        .build();
+
+    group_files.add(&row_auto_reload(&settings));
+
+
+    let group_export = PreferencesGroup::builder()
+        .title("Export")
+        .build();
+
+    group_export.add(&row_export_delimiter(&settings));
+    group_export.add(&row_export_null(&settings));
+    group_export.add(&row_export_blob(&settings));
+
+
     let group_appearance = PreferencesGroup::builder()
         .title("Appearance")
         .build();
@@ -51,6 +67,8 @@ pub fn show_preferences_dialog(parent: &Window) {
     group_appearance.add(&row_monospace(&settings));
 
     page.add(&group_rows_columns);
+    page.add(&group_files);
+    page.add(&group_export);
     page.add(&group_appearance);
 
 
@@ -97,6 +115,53 @@ fn row_separator(settings: &Settings) -> ComboRow {
 }
 
 
+fn row_export_delimiter(settings: &Settings) -> ComboRow {
+    combo_row_with_binding(
+        settings,
+        "export-delimiter",
+        "CSV Delimiter",
+        None,
+        &["Commas", "Semicolons", "Tabs"],
+    )
+}
+
+fn row_export_null(settings: &Settings) -> ComboRow {
+    combo_row_with_binding(
+        settings,
+        "export-null",
+        "NULL Values",
+        Some("Used in formats without a NULL type"),
+        &["Empty", "NULL"],
+    )
+}
+
+fn row_export_blob(settings: &Settings) -> ComboRow {
+    combo_row_with_binding(
+        settings,
+        "export-blob",
+        "BLOB Values",
+        None,
+        &["Empty", "Base64", "Hexadecimal"],
+    )
+}
+
+
+fn row_auto_reload(settings: &Settings) -> SwitchRow {
+    let switch = SwitchRow::builder()
+        .title("Reload Automatically")
+        .subtitle("When another program changes the open file")
+        .build();
+
+    settings.bind(
+        "auto-reload",
+        &switch,
+        "active"
+    ).build();
+
+    switch
+}
+
+
 fn row_monospace(settings: &Settings) -> SwitchRow {
     let switch = SwitchRow::builder()
         .title("Monospace Font")
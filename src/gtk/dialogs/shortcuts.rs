@@ -20,6 +20,7 @@ pub fn show_shortcuts_dialog(parent: &Window){
 
     shortcuts.add(section_general());
     shortcuts.add(section_table());
+    shortcuts.add(section_tabs());
 
     shortcuts.present(Some(parent));
 }
@@ -30,12 +31,16 @@ fn section_general() -> ShortcutsSection {
 
     let item_open   = ShortcutsItem::new("Open File", "<Primary>o");
     let item_menu   = ShortcutsItem::new("Open Menu", "F10");
+    let item_palette = ShortcutsItem::new("Command Palette", "<Primary>k");
+    let item_sidebar = ShortcutsItem::new("Show Sidebar", "F9");
     let item_reload = ShortcutsItem::new("Reload Window", "<Primary>r");
-    let item_close  = ShortcutsItem::new("Close Window", "<Primary>w");
+    let item_close  = ShortcutsItem::new("Close Window", "<Primary><Shift>w");
     let item_quit   = ShortcutsItem::new("Quit", "<Primary>q");
 
     section.add(item_open);
     section.add(item_menu);
+    section.add(item_palette);
+    section.add(item_sidebar);
     section.add(item_reload);
     section.add(item_close);
     section.add(item_quit);
@@ -48,10 +53,33 @@ fn section_table() -> ShortcutsSection {
     let section = ShortcutsSection::new(Some("Tables"));
 
     let item_copy = ShortcutsItem::new("Copy Row", "<Primary>c");
-    // let item_jump = ShortcutsItem::new("Jump To Row", "<Primary>l"); // TODO
+    let item_export = ShortcutsItem::new("Export Table", "<Primary>e");
+    let item_export_database = ShortcutsItem::new("Export Database", "<Primary><Shift>e");
+    let item_jump = ShortcutsItem::new("Jump to Row", "<Primary>l");
 
     section.add(item_copy);
-    // section.add(item_jump);
+    section.add(item_export);
+    section.add(item_export_database);
+    section.add(item_jump);
+
+    section
+}
+
+
+fn section_tabs() -> ShortcutsSection {
+    let section = ShortcutsSection::new(Some("Tabs"));
+
+    let item_next     = ShortcutsItem::new("Next Tab", "<Primary>Tab");
+    let item_previous = ShortcutsItem::new("Previous Tab", "<Primary><Shift>Tab");
+    let item_close    = ShortcutsItem::new("Close Tab", "<Primary>w");
+    let item_back     = ShortcutsItem::new("Back", "<Alt>Left");
+    let item_forward  = ShortcutsItem::new("Forward", "<Alt>Right");
+
+    section.add(item_next);
+    section.add(item_previous);
+    section.add(item_close);
+    section.add(item_back);
+    section.add(item_forward);
 
     section
 }
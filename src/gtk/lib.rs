@@ -5,6 +5,7 @@
 //   under the terms of the GNU General Public License v3 or any later version.
 
 
+use std::env;
 use std::error::Error;
 
 use gio::ApplicationFlags;
@@ -12,11 +13,19 @@ use gtk4::prelude::*;
 use libadwaita::Application;
 
 use crate::app::App;
+use crate::bobby::prelude::*;
+use crate::bobby::location::URI_SCHEME;
+use crate::log;
 use crate::gtk::actions::prelude::*;
+use crate::gtk::cache::{ cache_clear_copies, cache_init };
+use crate::gtk::recent::recent_init;
+use crate::gtk::session::{ session_init, session_restore };
+use crate::gtk::view::{ view_init, view_settings_flush };
 use crate::gtk::windows::prelude::*;
 use crate::gtk::windows::window::window_toggle_row_numbers;
 use crate::gtk::windows::window::window_toggle_row_order;
 use crate::gtk::windows::window::window_toggle_monospace_font;
+use crate::gtk::windows::window::window_toggle_sidebar;
 use crate::gui::Gui;
 
 
@@ -27,23 +36,33 @@ impl Gui for App {
             .flags(ApplicationFlags::HANDLES_OPEN)
             .build();
 
-        app.connect_open(move |app, files, _| {
+        // The hint holds what to show, like "table=orders&rowid=1234"
+        app.connect_open(move |app, files, hint| {
             for file in files {
-                if let Some(path) = file.path() &&
-                   let Some(window) = app.windows().iter()
-                       .find(|w| w.widget_name().to_string() == path.to_string_lossy())
-                {
-                    window.present();
-                } else {
-                    _ = window_handle_open(app, file, None);
+                let (file, target) = match file.uri_scheme().as_deref() {
+                    Some(URI_SCHEME) => match Location::from_uri(&file.uri()) {
+                        Ok(location) => (gio::File::for_path(&location.path), location),
+                        Err(e) => {
+                            log::error(&e);
+                            continue;
+                        },
+                    },
+                    _ => (file.clone(), Location::default().with_query(hint)),
+                };
+
+                if let Err(e) = window_handle_open(app, &file, &target) {
+                    log::error(&e.to_string());
                 }
             }
         });
 
+        // Pick up where the last session left off, when not opening files
         app.connect_activate(|app| {
             if let Some(window) = app.active_window() {
                 window.present();
-            } else if let Ok(window) = window_new(app, None, None) {
+            } else if !session_restore(app) &&
+                      let Ok(window) = window_new(app, None, None)
+            {
                 window.present();
             }
         });
@@ -72,14 +91,54 @@ impl Gui for App {
             }
         });
 
+        let app_handle = app.clone();
+        let _ = settings.connect_changed(Some("sidebar"), move |_settings, _key| {
+            for window in app_handle.windows() {
+                _ = window_toggle_sidebar(&window);
+            }
+        });
+
+
+        cache_init(app.upcast_ref(), &self.app_cache_home);
+        recent_init(app.upcast_ref(), &self.app_data_home);
+        session_init(&app, &self.app_state_home);
+        view_init(app.upcast_ref(), &self.app_state_home);
+
+        app.connect_shutdown(|_| {
+            view_settings_flush();
+            cache_clear_copies();
+        });
 
         app.add_action(&about_action(&app));
+        app.add_action(&missing_recent_action());
         app.add_action(&open_action(&app));
+        app.add_action(&open_recent_action(&app));
         app.add_action(&preferences_action(&app));
         app.add_action(&quit_action(&app));
+        app.add_action(&remove_missing_recent_action(&app));
+        app.add_action(&remove_recent_action(&app));
         app.add_action(&shortcuts_action(&app));
 
-        app.run();
+        let args: Vec<String> = env::args().collect();
+        let locations = self.cli_open_locations(&args)
+            .unwrap_or_else(|e| log::error_and_exit(&e.to_string()));
+
+        if !locations.iter().any(Location::has_target) {
+            app.run();
+            return Ok(());
+        }
+
+        // Pass the table and row along, also to an instance that's already running
+        app.register(None::<&gio::Cancellable>)?;
+
+        for location in &locations {
+            let file = gio::File::for_path(&location.path);
+            app.open(&[file], &location.query());
+        }
+
+        if !app.is_remote() {
+            app.run_with_args(args.get(..1).unwrap_or_default());
+        }
 
         Ok(())
     }
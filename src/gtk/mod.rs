@@ -8,37 +8,74 @@
 pub mod actions {
     pub mod prelude;
     pub mod app_about;
+    pub mod app_missing_recent;
     pub mod app_open;
+    pub mod app_open_recent;
     pub mod app_preferences;
     pub mod app_quit;
+    pub mod app_remove_missing_recent;
+    pub mod app_remove_recent;
     pub mod app_shortcuts;
+    pub mod win_check_integrity;
+    pub mod win_choose_columns;
     pub mod win_close;
+    pub mod win_close_tab;
+    pub mod win_copy_column;
+    pub mod win_copy_link;
     pub mod win_copy_val;
     pub mod win_copy_row;
+    pub mod win_copy_row_as;
+    pub mod win_export;
+    pub mod win_export_database;
+    pub mod win_fit_column;
+    pub mod win_follow;
+    pub mod win_go_back;
+    pub mod win_go_forward;
+    pub mod win_hide_column;
+    pub mod win_jump_to_row;
+    pub mod win_open_copy;
+    pub mod win_palette;
     pub mod win_reload;
+    pub mod win_retry;
     pub mod win_switch_table;
+    pub mod win_toggle_sidebar;
 }
 
 pub mod dialogs {
     pub mod about;
+    pub mod export;
     pub mod file;
+    pub mod integrity;
+    pub mod palette;
     pub mod preferences;
     pub mod shortcuts;
 }
 
 pub mod widgets {
     pub mod button;
+    pub mod columns;
     pub mod content;
     pub mod drop_target;
     pub mod item;
+    pub mod jump;
     pub mod menu;
+    pub mod range;
+    pub mod recent;
+    pub mod sidebar;
     pub mod switcher;
+    pub mod tabs;
 }
 
 pub mod windows {
+    pub mod history;
     pub mod prelude;
     pub mod window;
 }
 
+pub mod cache;
 pub mod lib;
+pub mod monitor;
+pub mod recent;
+pub mod session;
 pub mod util;
+pub mod view;
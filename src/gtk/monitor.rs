@@ -0,0 +1,90 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::error::Error;
+
+use gio::{
+    Cancellable,
+    File,
+    FileMonitor,
+    FileMonitorEvent,
+    FileMonitorFlags,
+    Settings,
+};
+
+use gtk4::prelude::*;
+use libadwaita::{
+    ApplicationWindow,
+    Banner,
+};
+
+use crate::bobby::prelude::*;
+use crate::gtk::actions::win_follow::is_following;
+
+
+const RATE_LIMIT_MS: i32 = 500;
+
+/// Watches the database and its WAL file, revealing the banner
+/// or reloading the window when someone else writes to them
+pub fn monitor_database(
+    window: &ApplicationWindow,
+    db: &Database,
+    banner: &Banner,
+) -> Result<Vec<FileMonitor>, Box<dyn Error>>
+{
+    let path = db.file.path().ok_or("Selected file has no local path")?;
+    let [path, wal, _] = Database::sidecar_paths(&path);
+
+    let mut monitors = Vec::new();
+
+    for path in [path, wal] {
+        let monitor = File::for_path(path)
+            .monitor_file(FileMonitorFlags::NONE, None::<&Cancellable>)?;
+
+        monitor.set_rate_limit(RATE_LIMIT_MS);
+
+        let window_weak = window.downgrade();
+        let db_handle = db.clone();
+        let banner_handle = banner.clone();
+
+        monitor.connect_changed(move |_, _, _, event| {
+            if !matches!(event,
+                FileMonitorEvent::Changed |
+                FileMonitorEvent::ChangesDoneHint |
+                FileMonitorEvent::Created |
+                FileMonitorEvent::Deleted)
+            {
+                return;
+            }
+
+            if banner_handle.is_revealed() || !db_handle.has_changed() {
+                return;
+            }
+
+            // Already picking up new rows by itself
+            if let Some(window) = window_weak.upgrade() &&
+               is_following(&window)
+            {
+                return;
+            }
+
+            let settings = Settings::new("studio.planetpeanut.Bobby"); // TODO
+
+            if settings.boolean("auto-reload") &&
+               let Some(window) = window_weak.upgrade()
+            {
+                _ = WidgetExt::activate_action(&window, "win.reload", None);
+            } else {
+                banner_handle.set_revealed(true);
+            }
+        });
+
+        monitors.push(monitor);
+    }
+
+    Ok(monitors)
+}
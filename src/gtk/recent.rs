@@ -0,0 +1,178 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::rc::Rc;
+
+use gio::{ Menu, MenuItem };
+
+use gtk4::prelude::*;
+use gtk4::Application;
+
+use crate::bobby::recent::{ RecentFile, RecentFiles };
+use crate::log;
+
+
+const FILE_NAME: &str = "recent-files";
+
+
+/// The recent files list, shared by all windows
+pub struct RecentStore {
+    path: PathBuf,
+    files: RefCell<RecentFiles>,
+
+    /// Shown in the main menu, and rebuilt on every change
+    menu: Menu,
+}
+
+pub type SharedRecent = Rc<RecentStore>;
+
+
+/// Loads the list kept in `app_data_home`
+pub fn recent_init(app: &Application, app_data_home: &Path) {
+    let path = app_data_home.join(FILE_NAME);
+
+    let files = RecentFiles::load(&path).unwrap_or_else(|e| {
+        log::error(&e.to_string());
+        RecentFiles::default()
+    });
+
+    let store = SharedRecent::new(RecentStore {
+        path,
+        files: RefCell::new(files),
+        menu: Menu::new(),
+    });
+
+    recent_menu_update(&store);
+
+    // SAFETY: Only ever set as SharedRecent
+    unsafe {
+        app.set_data("recent", store);
+    }
+}
+
+
+fn app_recent(app: &Application) -> Option<SharedRecent> {
+    // SAFETY: Read back as SharedRecent, set in recent_init()
+    unsafe {
+        app.data::<SharedRecent>("recent")
+            .map(|store| store.as_ref().clone())
+    }
+}
+
+
+pub fn recent_files(app: &Application) -> RecentFiles {
+    app_recent(app)
+        .map(|store| store.files.borrow().clone())
+        .unwrap_or_default()
+}
+
+
+/// The menu model of recent files, which updates by itself
+pub fn recent_menu(app: &Application) -> Option<Menu> {
+    app_recent(app).map(|store| store.menu.clone())
+}
+
+
+/// Moves `path` to the top of the list, as just opened
+pub fn recent_add(app: &Application, path: &Path, table_name: Option<String>) {
+    let file = RecentFile {
+        path: path.to_path_buf(),
+        table_name,
+        opened: chrono::Utc::now().timestamp(),
+        size: fs::metadata(path).map(|m| m.len()).unwrap_or_default(),
+    };
+
+    recent_change(app, |files| {
+        files.add(file);
+        true
+    });
+}
+
+
+/// The table `path` was left at
+pub fn recent_table(app: &Application, path: &Path) -> Option<String> {
+    app_recent(app)?
+        .files
+        .borrow()
+        .find(path)
+        .and_then(|file| file.table_name.clone())
+}
+
+
+pub fn recent_set_table(app: &Application, path: &Path, table_name: &str) {
+    recent_change(app, |files| files.set_table(path, table_name));
+}
+
+
+pub fn recent_remove(app: &Application, path: &Path) {
+    recent_change(app, |files| files.remove(path).is_some());
+}
+
+
+pub fn recent_remove_missing(app: &Application) {
+    recent_change(app, |files| {
+        files.remove_missing();
+        true
+    });
+}
+
+
+/// Applies `change` and saves the list, when `change` says it changed
+fn recent_change(app: &Application, change: impl FnOnce(&mut RecentFiles) -> bool) {
+    let Some(store) = app_recent(app) else {
+        return;
+    };
+
+    if !change(&mut store.files.borrow_mut()) {
+        return;
+    }
+
+    if let Err(e) = store.files.borrow().save(&store.path) {
+        log::error(&e.to_string());
+    }
+
+    recent_menu_update(&store);
+}
+
+
+/// Missing files point to an action that's always disabled, which greys them out
+fn recent_menu_update(store: &RecentStore) {
+    let section = Menu::new();
+
+    for file in store.files.borrow().files() {
+        let action = match file.exists() {
+            true => "app.open-recent",
+            false => "app.missing-recent",
+        };
+
+        let item = MenuItem::new(Some(&recent_file_title(file)), None);
+        item.set_action_and_target_value(Some(action), Some(&file.path.to_string_lossy().to_variant()));
+        section.append_item(&item);
+    }
+
+    let cleanup = Menu::new();
+
+    if store.files.borrow().files().iter().any(|file| !file.exists()) {
+        cleanup.append(Some("Remove Missing Files"), Some("app.remove-missing-recent"));
+    }
+
+    // Listeners rebuild when the files section comes back at the top
+    store.menu.remove_all();
+    store.menu.append_section(None, &section);
+    store.menu.append_section(None, &cleanup);
+}
+
+
+pub fn recent_file_title(file: &RecentFile) -> String {
+    file.path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| file.path.to_string_lossy().to_string())
+}
@@ -0,0 +1,86 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::path::{ Path, PathBuf };
+
+use gtk4::prelude::*;
+use gtk4::Window;
+
+use libadwaita::Application;
+
+use crate::bobby::session::Session;
+use crate::gtk::cache::cache_contains;
+use crate::gtk::windows::prelude::*;
+use crate::log;
+
+
+const FILE_NAME: &str = "session";
+
+
+/// Keeps the session in `app_state_home`, as it's not worth backing up
+pub fn session_init(app: &Application, app_state_home: &Path) {
+    // SAFETY: Only ever set as PathBuf
+    unsafe {
+        app.set_data("session", app_state_home.join(FILE_NAME));
+    }
+}
+
+
+fn session_path(app: &Application) -> Option<PathBuf> {
+    // SAFETY: Read back as PathBuf, set in session_init()
+    unsafe {
+        app.data::<PathBuf>("session")
+            .map(|path| path.as_ref().clone())
+    }
+}
+
+
+/// Saves the open windows, leaving out `closing` unless it's the last one,
+/// so that closing the last window is the same as quitting
+pub fn session_save(app: &Application, closing: Option<&Window>) {
+    let Some(path) = session_path(app) else {
+        return;
+    };
+
+    let windows = app.windows();
+    let is_last = windows.iter().all(|window| Some(window) == closing);
+
+    let session = Session {
+        windows: windows
+            .iter()
+            .filter(|window| is_last || Some(*window) != closing)
+            .filter_map(window_session)
+            .filter(|window| !cache_contains(&window.path)) // Removed when quitting
+            .collect(),
+    };
+
+    if let Err(e) = session.save(&path) {
+        log::error(&e.to_string());
+    }
+}
+
+
+/// Reopens the windows of the last session, returning
+/// whether there were any
+pub fn session_restore(app: &Application) -> bool {
+    let Some(path) = session_path(app) else {
+        return false;
+    };
+
+    let session = Session::load(&path).unwrap_or_else(|e| {
+        log::error(&e.to_string());
+        Session::default()
+    });
+
+    for window in &session.windows {
+        if let Err(e) = window_restore_session(app, window) {
+            log::error(&e.to_string());
+        }
+    }
+
+    !app.windows().is_empty()
+}
@@ -10,9 +10,16 @@ use std::error::Error;
 use gtk4::prelude::*;
 use gtk4::{
     gdk::Display,
+    BitsetIter,
+    ColumnView,
+    MultiSelection,
     Widget,
 };
 
+use libadwaita::TabView;
+
+use crate::bobby::sqlite::cache::DatabaseCacheModel;
+
 
 // TODO: Use generics and return Option<T>
 pub fn widget_by_name(name: &str, parent: &Widget) -> Option<Widget> {
@@ -46,11 +53,16 @@ pub fn copy_to_clipboard(s: &str) -> Result<(), Box<dyn Error>> {
 
 
 // TODO: Use widget_by_name() everywhere
+/// Only looks in the selected tab, as the others hold tables that aren't shown
 pub fn find_column_view(root: &Widget) -> Option<gtk4::ColumnView> {
     if let Ok(column_view) = root.clone().downcast::<gtk4::ColumnView>() {
         return Some(column_view);
     }
 
+    if let Some(tab_view) = root.downcast_ref::<TabView>() {
+        return find_column_view(&tab_view.selected_page()?.child());
+    }
+
     let mut child = root.first_child();
 
     while let Some(widget) = child {
@@ -63,3 +75,80 @@ pub fn find_column_view(root: &Widget) -> Option<gtk4::ColumnView> {
 
     None
 }
+
+
+pub fn find_tab_view(root: &Widget) -> Option<TabView> {
+    widget_by_name("tabs", root)?
+        .downcast::<TabView>().ok()
+}
+
+
+pub fn find_cache_model(root: &Widget) -> Option<DatabaseCacheModel> {
+    find_column_view(root)?
+        .model()?
+        .downcast::<MultiSelection>().ok()?
+        .model()?
+        .downcast::<DatabaseCacheModel>().ok()
+}
+
+
+/// The selected rows as runs of (first position, number of rows), found from
+/// where each run starts and ends rather than from every selected position
+pub fn selected_runs(column_view: &ColumnView) -> Vec<(u32, u32)> {
+    let Some(model) = column_view.model() else {
+        return Vec::new();
+    };
+
+    let selection = model.selection();
+
+    // Selected, but the row before isn't
+    let starts = selection.copy();
+    let after = selection.copy();
+    after.shift_right(1);
+    starts.subtract(&after);
+
+    // Selected, but the row after isn't
+    let ends = selection.copy();
+    let before = selection.copy();
+    before.shift_left(1);
+    ends.subtract(&before);
+
+    let (Some((starts, first)), Some((ends, last))) =
+        (BitsetIter::init_first(&starts), BitsetIter::init_first(&ends)) else {
+        return Vec::new();
+    };
+
+    std::iter::once(first).chain(starts)
+        .zip(std::iter::once(last).chain(ends))
+        .map(|(first, last)| (first, last - first + 1))
+        .collect()
+}
+
+
+/// Position of the topmost selected row
+pub fn first_selected_row(column_view: &ColumnView) -> Option<u32> {
+    let selection = column_view.model()?.selection();
+
+    match selection.is_empty() {
+        true => None,
+        false => Some(selection.minimum()),
+    }
+}
+
+
+/// Positions of the selected rows from top to bottom, stopping after `limit`,
+/// along with how many are selected
+pub fn selected_rows_limit(column_view: &ColumnView, limit: usize) -> (Vec<u32>, u64) {
+    let Some(model) = column_view.model() else {
+        return (Vec::new(), 0);
+    };
+
+    let selection = model.selection();
+
+    let positions = match BitsetIter::init_first(&selection) {
+        Some((iter, first)) => std::iter::once(first).chain(iter).take(limit).collect(),
+        None => Vec::new(),
+    };
+
+    (positions, selection.size())
+}
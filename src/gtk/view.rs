@@ -0,0 +1,231 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::{ Cell, RefCell };
+use std::path::{ Path, PathBuf };
+use std::rc::Rc;
+use std::time::Duration;
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    ColumnView,
+    ColumnViewColumn,
+};
+
+use crate::bobby::prelude::*;
+use crate::bobby::view::{ ColumnSettings, TableSettings, ViewSettings };
+use crate::log;
+
+
+const FILE_NAME: &str = "views";
+
+/// Resizing a column changes its width many times over
+const SAVE_DELAY: Duration = Duration::from_secs(1);
+
+
+/// The view settings, shared by all windows
+struct ViewStore {
+    path: PathBuf,
+    settings: RefCell<ViewSettings>,
+    save_pending: Cell<bool>,
+}
+
+type SharedViewStore = Rc<ViewStore>;
+
+
+/// Loads the settings kept in `app_state_home`
+pub fn view_init(app: &gio::Application, app_state_home: &Path) {
+    let path = app_state_home.join(FILE_NAME);
+
+    let settings = ViewSettings::load(&path).unwrap_or_else(|e| {
+        log::error(&e.to_string());
+        ViewSettings::default()
+    });
+
+    let store = SharedViewStore::new(ViewStore {
+        path,
+        settings: RefCell::new(settings),
+        save_pending: Cell::new(false),
+    });
+
+    // SAFETY: Only ever set as SharedViewStore
+    unsafe {
+        app.set_data("views", store);
+    }
+}
+
+
+fn view_store() -> Option<SharedViewStore> {
+    let app = gio::Application::default()?;
+
+    // SAFETY: Read back as SharedViewStore, set in view_init()
+    unsafe {
+        app.data::<SharedViewStore>("views")
+            .map(|store| store.as_ref().clone())
+    }
+}
+
+
+fn view_store_changed(store: &SharedViewStore) {
+    if store.save_pending.replace(true) {
+        return;
+    }
+
+    let store = store.clone();
+
+    glib::timeout_add_local_once(SAVE_DELAY, move || view_store_save(&store));
+}
+
+
+fn view_store_save(store: &ViewStore) {
+    store.save_pending.set(false);
+
+    if let Err(e) = store.settings.borrow().save(&store.path) {
+        log::error(&e.to_string());
+    }
+}
+
+
+/// Saves changes that are still waiting to be saved, as when quitting
+pub fn view_settings_flush() {
+    if let Some(store) = view_store() &&
+       store.save_pending.get()
+    {
+        view_store_save(&store);
+    }
+}
+
+
+/// Puts the columns of `table` back the way they were left, and keeps track of
+/// changes to them. `names` holds the column names by column index.
+pub fn view_settings_setup(
+    column_view: &ColumnView,
+    database: &Database,
+    table: &Table,
+    names: Rc<Vec<String>>,
+) {
+    let Some(store) = view_store() else {
+        return;
+    };
+
+    let Some(path) = database.file.path() else {
+        return;
+    };
+
+    let fingerprint = match database.schema_fingerprint(table) {
+        Ok(fingerprint) => fingerprint,
+        Err(e) => {
+            log::error(&e.to_string());
+            return;
+        },
+    };
+
+    let table_name = table.name();
+
+    if let Some(settings) = store.settings.borrow().table(&path, &table_name, fingerprint) {
+        view_settings_apply(column_view, settings, &names);
+    }
+
+    let record = Rc::new(move |column_view: &ColumnView| {
+        let settings = TableSettings {
+            path: path.clone(),
+            table_name: table_name.clone(),
+            fingerprint,
+            columns: view_columns(column_view)
+                .iter()
+                .filter_map(|column| {
+                    let index = column_index(column).filter(|index| *index > 0)?;
+
+                    Some(ColumnSettings {
+                        name: names.get(index)?.clone(),
+                        width: column.fixed_width(),
+                        hidden: !column.is_visible(),
+                    })
+                })
+                .collect(),
+        };
+
+        if store.settings.borrow_mut().set_table(settings) {
+            view_store_changed(&store);
+        }
+    });
+
+    for column in view_columns(column_view) {
+        if column_index(&column) == Some(0) {
+            continue; // Row numbers follow the preferences
+        }
+
+        for property in ["fixed-width", "visible"] {
+            let record = record.clone();
+            let column_view_weak = column_view.downgrade();
+
+            column.connect_notify_local(Some(property), move |_, _| {
+                if let Some(column_view) = column_view_weak.upgrade() {
+                    record(&column_view);
+                }
+            });
+        }
+    }
+
+    // Columns are only removed, and not added back, when the view goes away
+    let column_view_weak = column_view.downgrade();
+
+    column_view.columns().connect_items_changed(move |_, _, _, added| {
+        if added > 0 &&
+           let Some(column_view) = column_view_weak.upgrade()
+        {
+            record(&column_view);
+        }
+    });
+}
+
+
+fn view_settings_apply(column_view: &ColumnView, settings: &TableSettings, names: &[String]) {
+    let columns = view_columns(column_view);
+
+    // After the row numbers
+    let mut position = 1;
+
+    for saved in &settings.columns {
+        let Some(column) = columns.iter().find(|column| {
+            column_index(column).and_then(|index| names.get(index)) == Some(&saved.name)
+        }) else {
+            continue;
+        };
+
+        column.set_fixed_width(saved.width);
+        column.set_visible(!saved.hidden);
+
+        column_view.insert_column(position, column);
+        position += 1;
+    }
+}
+
+
+/// The columns in the order they're shown in
+pub fn view_columns(column_view: &ColumnView) -> Vec<ColumnViewColumn> {
+    let columns = column_view.columns();
+
+    (0..columns.n_items())
+        .filter_map(|i| columns.item(i).and_downcast::<ColumnViewColumn>())
+        .collect()
+}
+
+
+/// The column that shows column `index`, wherever it was moved to
+pub fn view_column(column_view: &ColumnView, index: usize) -> Option<ColumnViewColumn> {
+    view_columns(column_view)
+        .into_iter()
+        .find(|column| column_index(column) == Some(index))
+}
+
+
+/// Which column of the table `column` shows, with 0 for the row numbers
+pub fn column_index(column: &ColumnViewColumn) -> Option<usize> {
+    column.id()?.parse().ok()
+}
@@ -9,6 +9,8 @@ use gtk4::prelude::*;
 use gtk4::{
     Align,
     Button,
+    Orientation,
+    ToggleButton,
     Window,
 };
 
@@ -32,3 +34,51 @@ pub fn button_open_new(window: &Window) -> Button {
 
     button
 }
+
+
+pub fn button_follow_new() -> ToggleButton {
+    ToggleButton::builder()
+        .icon_name("go-bottom-symbolic")
+        .action_name("win.follow")
+        .tooltip_text("Follow New Rows")
+        .name("follow")
+        .build()
+}
+
+
+/// Back and forward through the places visited in the window
+pub fn button_navigation_new() -> gtk4::Box {
+    let navigation = gtk4::Box::builder()
+        .orientation(Orientation::Horizontal)
+        .css_classes(["linked"])
+        .name("navigation")
+        .build();
+
+    navigation.append(
+        &Button::builder()
+            .icon_name("go-previous-symbolic")
+            .action_name("win.back")
+            .tooltip_text("Back")
+            .build()
+    );
+
+    navigation.append(
+        &Button::builder()
+            .icon_name("go-next-symbolic")
+            .action_name("win.forward")
+            .tooltip_text("Forward")
+            .build()
+    );
+
+    navigation
+}
+
+
+pub fn button_recovery_new(label: &str, action_name: &str) -> Button {
+    Button::builder()
+        .label(label)
+        .action_name(action_name)
+        .css_classes(["pill"])
+        .halign(Align::Center)
+        .build()
+}
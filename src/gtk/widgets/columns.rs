@@ -0,0 +1,179 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gio::Settings;
+
+use gtk4::prelude::*;
+use gtk4::{
+    Button,
+    CheckButton,
+    ColumnView,
+    ColumnViewColumn,
+    Label,
+    ListBox,
+    MenuButton,
+    Orientation,
+    Popover,
+    PolicyType,
+    ScrolledWindow,
+    SearchEntry,
+    SelectionMode,
+    Widget,
+};
+
+use crate::gtk::util::find_column_view;
+use crate::gtk::view::{ column_index, view_columns };
+
+use super::item::MARGIN;
+
+
+pub const WIDGET_NAME: &str = "columns";
+
+const FIT_MIN_WIDTH: i32 = 48;
+const FIT_MAX_WIDTH: i32 = 480;
+
+/// Room for the cell's own padding around the label
+const FIT_PADDING: i32 = 12;
+
+
+/// Picks which columns to show, with a filter for tables with many of them.
+/// The list is built each time it's shown, from the table shown then.
+pub fn button_columns_new() -> MenuButton {
+    let search_entry = SearchEntry::builder()
+        .placeholder_text("Filter Columns")
+        .build();
+
+    let list = ListBox::builder()
+        .selection_mode(SelectionMode::None)
+        .css_classes(["navigation-sidebar"])
+        .build();
+
+    let scrolled_window = ScrolledWindow::builder()
+        .hscrollbar_policy(PolicyType::Never)
+        .propagate_natural_height(true)
+        .max_content_height(360)
+        .min_content_width(240)
+        .child(&list)
+        .build();
+
+    let show_all = Button::builder()
+        .label("Show All")
+        .build();
+
+    let layout = gtk4::Box::new(Orientation::Vertical, 6);
+    layout.append(&search_entry);
+    layout.append(&scrolled_window);
+    layout.append(&show_all);
+
+    let popover = Popover::builder()
+        .child(&layout)
+        .build();
+
+    let button = MenuButton::builder()
+        .icon_name("view-more-horizontal-symbolic")
+        .tooltip_text("Choose Columns")
+        .popover(&popover)
+        .name(WIDGET_NAME)
+        .build();
+
+
+    let search_entry_handle = search_entry.clone();
+
+    list.set_filter_func(move |row| {
+        let query = search_entry_handle.text().to_lowercase();
+
+        row.child()
+            .and_downcast::<CheckButton>()
+            .and_then(|check| check.label())
+            .is_some_and(|label| label.to_lowercase().contains(&query))
+    });
+
+    let list_handle = list.clone();
+
+    search_entry.connect_search_changed(move |_| {
+        list_handle.invalidate_filter();
+    });
+
+    let button_handle = button.clone();
+    let list_handle = list.clone();
+    let search_entry_handle = search_entry.clone();
+
+    popover.connect_show(move |_| {
+        search_entry_handle.set_text("");
+        list_handle.remove_all();
+
+        let Some(column_view) = button_handle.root().and_then(|root| find_column_view(root.upcast_ref())) else {
+            return;
+        };
+
+        // Listed in the order they're shown in
+        for column in view_columns(&column_view) {
+            if column_index(&column).is_none_or(|index| index == 0) {
+                continue; // Row numbers follow the preferences
+            }
+
+            let check = CheckButton::builder()
+                .label(column.title().unwrap_or_default())
+                .build();
+
+            column
+                .bind_property("visible", &check, "active")
+                .bidirectional()
+                .sync_create()
+                .build();
+
+            list_handle.append(&check);
+        }
+    });
+
+    let button_handle = button.clone();
+
+    show_all.connect_clicked(move |_| {
+        if let Some(column_view) = button_handle.root().and_then(|root| find_column_view(root.upcast_ref())) {
+            for column in view_columns(&column_view) {
+                if column_index(&column).is_some_and(|index| index > 0) {
+                    column.set_visible(true);
+                }
+            }
+        }
+    });
+
+    button
+}
+
+
+/// A width for `column` that fits its title and `texts`, the cells that are loaded
+pub fn column_fit_width(column_view: &ColumnView, column: &ColumnViewColumn, texts: &[String]) -> i32 {
+    let settings = Settings::new("studio.planetpeanut.Bobby"); // TODO
+    let monospace_font: bool = settings.get("monospace-font");
+
+    let label = Label::new(None); // Find out rendering text width
+
+    if monospace_font {
+        label.add_css_class("monospace");
+    }
+
+    // Cells only show their first line
+    let cells = texts
+        .iter()
+        .map(|text| text_width(&label, text.lines().next().unwrap_or_default()))
+        .max()
+        .unwrap_or(0);
+
+    let title = text_width(column_view, &column.title().unwrap_or_default());
+
+    (MARGIN + cells.max(title) + MARGIN + FIT_PADDING).clamp(FIT_MIN_WIDTH, FIT_MAX_WIDTH)
+}
+
+
+fn text_width(widget: &impl IsA<Widget>, text: &str) -> i32 {
+    // Anything wider won't fit anyway
+    let text = text.chars().take(FIT_MAX_WIDTH as usize).collect::<String>();
+    let layout = widget.create_pango_layout(Some(&text));
+
+    layout.pixel_size().0
+}
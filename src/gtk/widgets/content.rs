@@ -5,8 +5,9 @@
 //   under the terms of the GNU General Public License v3 or any later version.
 
 
-use std::cell::Ref;
+use std::cell::{ Ref, RefCell };
 use std::error::Error;
+use std::rc::Rc;
 
 use gio::{
     Menu,
@@ -26,12 +27,13 @@ use gtk4::{
     PopoverMenu,
     PickFlags,
     ScrolledWindow,
+    MultiSelection,
     SignalListItemFactory,
-    SingleSelection,
 };
 
 use crate::bobby::prelude::*;
 use crate::bobby::sqlite::cache::DatabaseCacheModel;
+use crate::gtk::view::view_settings_setup;
 
 use super::item::{
     bind_index_list_item,
@@ -40,6 +42,11 @@ use super::item::{
     setup_list_item,
     SYMBOL_KEY,
 };
+use super::range::{
+    cell_range_setup,
+    SharedCellRange,
+    SharedColumnOrder,
+};
 
 
 pub fn content_new(
@@ -51,17 +58,20 @@ pub fn content_new(
     let monospace_font: bool = settings.get("monospace-font");
 
     let model = DatabaseCacheModel::from_database(database, table);
-    let selection = SingleSelection::new(Some(model));
+    let selection = MultiSelection::new(Some(model));
+    let cell_range: SharedCellRange = Rc::new(RefCell::new(None));
+    let column_order: SharedColumnOrder = Rc::default();
 
+    // Dragging with Alt held selects a block of cells instead
     let column_view = ColumnView::builder()
-        .enable_rubberband(false)
+        .enable_rubberband(true)
         .focusable(true)
         .has_tooltip(true)
         .model(&selection)
-        .reorderable(false)
+        .reorderable(true)
         .show_column_separators(true)
         .show_row_separators(true)
-        .single_click_activate(true)
+        .single_click_activate(false)
         .tab_behavior(ListTabBehavior::Cell)
         .build();
 
@@ -95,6 +105,8 @@ pub fn content_new(
             });
         } else {
             let primary_key = column.primary_key;
+            let cell_range = cell_range.clone();
+            let column_order = column_order.clone();
 
             factory.connect_setup(move |_factory, obj| {
                 if let Err(e) = setup_list_item(obj, monospace_font) {
@@ -103,7 +115,7 @@ pub fn content_new(
             });
 
             factory.connect_bind(move |_factory, obj| {
-                if let Err(e) = bind_list_item(obj, column_index, primary_key) {
+                if let Err(e) = bind_list_item(obj, column_index, primary_key, &cell_range, &column_order) {
                     eprintln!("Failed to bind index list item: {e}");
                 }
             });
@@ -117,6 +129,10 @@ pub fn content_new(
             .expand(is_last_column)
             .build();
 
+        if !is_index_column {
+            view_column.set_header_menu(Some(&header_menu_new(column_index - 1)));
+        }
+
         if column.primary_key {
             let title = format!("{} {}", &column.name, SYMBOL_KEY);
             view_column.set_title(Some(&title));
@@ -175,7 +191,11 @@ pub fn content_new(
     });
 
 
+    let names = columns.iter().map(|column| column.name.clone()).collect();
+    view_settings_setup(&column_view, database, table, Rc::new(names));
+
     column_view.add_controller(click);
+    cell_range_setup(&column_view, &cell_range, &column_order);
     column_view.grab_focus();
 
     let scrolled_window = ScrolledWindow::new();
@@ -195,14 +215,10 @@ fn content_clicked(
     column_view: &ColumnView,
 ) -> Result<(), Box<dyn Error>>
 {
-    let model = column_view
+    let selection = column_view
         .model()
         .ok_or("Missing model on ColumnView")?;
 
-    let single_selection = model
-        .downcast::<SingleSelection>()
-        .map_err(|_| "Model is not a gtk4::SingleSelection")?;
-
     let picked = column_view
         .pick(x, y, PickFlags::NON_TARGETABLE)
         .ok_or("Could not pick Widget")?;
@@ -212,18 +228,64 @@ fn content_clicked(
         .downcast::<Label>()
         .map_err(|_| "Widget is not a gtk4::Label")?;
 
-    let row = single_selection.selected() as usize;
+    // SAFETY: Set as u32 when the item was bound
+    let row = unsafe {
+        label
+            .data::<u32>("position")
+            .map(|position| *position.as_ref())
+            .ok_or("Missing position on Label")?
+    };
+
+    // Act on the clicked row, unless it's part of a larger selection
+    if !selection.is_selected(row) {
+        selection.select_item(row, true);
+    }
+
+    let n_selected = selection.selection().size();
     let col = label.widget_name().parse::<usize>()?;
 
     if let Some(col) = col.checked_sub(1) {
-        context_menu_open(gesture, col, row, x, y);
+        context_menu_open(gesture, col, row as usize, n_selected, x, y);
     }
 
     Ok(())
 }
 
 
-fn context_menu_open(gesture: &GestureClick, col_index: usize, row_index: usize, x: f64, y: f64) {
+/// Copies the column straight from the database, as not all rows are cached.
+/// Also hides and fits the column, or picks which ones to show.
+fn header_menu_new(col_index: usize) -> Menu {
+    let menu = Menu::new();
+
+    let lines_section = Menu::new();
+    lines_section.append(Some("Copy Column Values"), Some(&format!("win.copy-column::{col_index}:lines")));
+    lines_section.append(Some("Copy Distinct Values"), Some(&format!("win.copy-column::{col_index}:distinct-lines")));
+
+    let in_section = Menu::new();
+    in_section.append(Some("Copy as IN List"), Some(&format!("win.copy-column::{col_index}:in")));
+    in_section.append(Some("Copy Distinct as IN List"), Some(&format!("win.copy-column::{col_index}:distinct-in")));
+
+    let column_section = Menu::new();
+    column_section.append(Some("Auto-Fit Width"), Some(&format!("win.fit-column::{col_index}")));
+    column_section.append(Some("Hide Column"), Some(&format!("win.hide-column::{col_index}")));
+    column_section.append(Some("Choose Columns…"), Some("win.choose-columns"));
+
+    menu.append_section(None, &lines_section);
+    menu.append_section(None, &in_section);
+    menu.append_section(None, &column_section);
+
+    menu
+}
+
+
+fn context_menu_open(
+    gesture: &GestureClick,
+    col_index: usize,
+    row_index: usize,
+    n_selected: u64,
+    x: f64,
+    y: f64,
+) {
     if let Some(widget) = gesture.widget() {
         let menu = Menu::new();
 
@@ -232,16 +294,29 @@ fn context_menu_open(gesture: &GestureClick, col_index: usize, row_index: usize,
             Some(&format!("win.copy-val::{}:{}", row_index, col_index)),
         );
 
+        let copy_row_label = match n_selected {
+            1 => "Copy Row".to_string(),
+            n => format!("Copy {n} Rows"),
+        };
+
         menu.append(
-            Some("Copy Row"),
+            Some(&copy_row_label),
             Some("win.copy-row")
         );
 
-        // TODO: Also prepend column headers in Markdown mode
-        // menu.append(
-        //     Some("Copy Rows"),
-        //     Some(&format!("win.copy-rows::{}", row_index))
-        // );
+        let copy_as_menu = Menu::new();
+        copy_as_menu.append(Some("JSON Object"), Some("win.copy-row-as::json"));
+        copy_as_menu.append(Some("INSERT Statement"), Some("win.copy-row-as::insert"));
+        copy_as_menu.append(Some("UPDATE Statement"), Some("win.copy-row-as::update"));
+        copy_as_menu.append(Some("WHERE Clause"), Some("win.copy-row-as::where"));
+
+        menu.append_submenu(Some("Copy As"), &copy_as_menu);
+
+        let link_section = Menu::new();
+        link_section.append(Some("Copy Link to Cell"), Some(&format!("win.copy-link::{row_index}:{col_index}")));
+        link_section.append(Some("Copy Link to Row"), Some(&format!("win.copy-link::{row_index}")));
+
+        menu.append_section(None, &link_section);
 
         let popover = PopoverMenu::builder()
             .has_arrow(false)
@@ -258,9 +333,7 @@ fn context_menu_open(gesture: &GestureClick, col_index: usize, row_index: usize,
 
 pub fn get_row(column_view: ColumnView, position: usize) -> Option<Row> {
     let model = column_view.model()?;
-    let selection = model.downcast_ref::<SingleSelection>()?;
-
-    let item = selection
+    let item = model
         .item(position as u32)
         .and_then(|o| o.downcast::<BoxedAnyObject>().ok())?;
 
@@ -285,6 +358,9 @@ pub fn content_force_redraw(column_view: &ColumnView) {
         }
     }
 
+    // Leave hidden columns hidden
+    cols.retain(|c| c.is_visible());
+
     for c in &cols {
         c.set_visible(false);
     }
@@ -20,6 +20,12 @@ use gtk4::{
 
 use crate::bobby::prelude::*;
 
+use super::range::{
+    cell_highlight,
+    SharedCellRange,
+    SharedColumnOrder,
+};
+
 
 // U+25C7 "White Diamond"
 pub const MARGIN: i32 = 4;
@@ -113,6 +119,8 @@ pub fn bind_list_item(
     obj: &Object,
     column_index: usize,
     primary_key: bool,
+    cell_range: &SharedCellRange,
+    column_order: &SharedColumnOrder,
 ) -> Result<(), Box<dyn Error>>
 {
     let list_item = obj
@@ -143,6 +151,25 @@ pub fn bind_list_item(
     }
 
 
+    // SAFETY: Only ever read back as u32, to tell which row was clicked
+    unsafe {
+        label.set_data("position", list_item.position());
+    }
+
+    // Ranges go by where the column is shown
+    let column_position = column_order
+        .borrow()
+        .iter()
+        .position(|i| *i == column_index - 1);
+
+    let in_range = cell_range
+        .borrow()
+        .zip(column_position)
+        .is_some_and(|(r, column)| !r.is_single() && r.contains(list_item.position(), column));
+
+    cell_highlight(&label, in_range);
+
+
     let name = column_index.to_string();
 
     // Possible cell reuse
@@ -162,6 +189,17 @@ pub fn bind_list_item(
     }
 
 
+    // Changed since the last reload
+    label.remove_css_class("success");
+    label.remove_css_class("warning");
+
+    match row.change {
+        Some(RowChange::Inserted) => label.add_css_class("success"),
+        Some(RowChange::Modified) => label.add_css_class("warning"),
+        None => {},
+    }
+
+
     if let Some(parent) = label.parent() {
         let tooltip_text = {
             let s = match cell {
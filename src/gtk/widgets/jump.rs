@@ -0,0 +1,102 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gtk4::prelude::*;
+use gtk4::{
+    Entry,
+    InputPurpose,
+    MenuButton,
+    Orientation,
+    Popover,
+};
+
+use libadwaita::{
+    Toggle,
+    ToggleGroup,
+};
+
+
+const WIDGET_NAME: &str = "jump";
+
+/// Kinds of row to jump to, as understood by RowTarget::new()
+const KINDS: [(&str, &str, &str); 3] = [
+    ("number", "Row", "Row Number"),
+    ("rowid", "Rowid", "Rowid"),
+    ("key", "Key", "Primary Key"),
+];
+
+
+/// Asks for a row to jump to, and passes it on to the win.jump-to-row action
+pub fn button_jump_new() -> MenuButton {
+    let toggle_group = ToggleGroup::new();
+
+    for (name, label, _) in KINDS {
+        toggle_group.add(
+            Toggle::builder()
+                .name(name)
+                .label(label)
+                .build()
+        );
+    }
+
+    toggle_group.set_active_name(Some(KINDS[0].0));
+
+    let entry = Entry::builder()
+        .placeholder_text(KINDS[0].2)
+        .input_purpose(InputPurpose::Digits)
+        .activates_default(false)
+        .build();
+
+    let entry_handle = entry.clone();
+
+    toggle_group.connect_active_name_notify(move |toggle_group| {
+        let kind = toggle_group.active_name().unwrap_or_default();
+
+        if let Some((name, _, placeholder)) = KINDS.iter().find(|(name, _, _)| *name == kind) {
+            entry_handle.set_placeholder_text(Some(placeholder));
+            entry_handle.set_input_purpose(match *name {
+                "key" => InputPurpose::FreeForm,
+                _ => InputPurpose::Digits,
+            });
+        }
+
+        entry_handle.grab_focus();
+    });
+
+    let layout = gtk4::Box::new(Orientation::Vertical, 12);
+    layout.append(&toggle_group);
+    layout.append(&entry);
+
+    let popover = Popover::builder()
+        .child(&layout)
+        .build();
+
+    let entry_handle = entry.clone();
+
+    popover.connect_show(move |_| {
+        entry_handle.grab_focus();
+    });
+
+    let popover_handle = popover.clone();
+
+    entry.connect_activate(move |entry| {
+        let kind = toggle_group.active_name().unwrap_or_default();
+        let target = format!("{kind}:{}", entry.text());
+
+        popover_handle.popdown();
+        entry.set_text("");
+
+        _ = entry.activate_action("win.jump-to-row", Some(&target.to_variant()));
+    });
+
+    MenuButton::builder()
+        .icon_name("find-location-symbolic")
+        .tooltip_text("Jump to Row")
+        .popover(&popover)
+        .name(WIDGET_NAME)
+        .build()
+}
@@ -18,6 +18,8 @@ use gtk4::{
 
 use libadwaita::Application;
 
+use crate::gtk::recent::recent_menu;
+
 
 pub fn main_menu_new(app: &Application) -> MenuButton {
     let button = MenuButton::new();
@@ -36,9 +38,30 @@ pub fn main_menu_new(app: &Application) -> MenuButton {
     app.set_accels_for_action("app.open-menu", &["F10"]);
 
     let menu = Menu::new();
-    menu.append(Some("Preferences"), Some("app.preferences"));
-    menu.append(Some("Keyboard Shortcuts"), Some("app.shortcuts"));
-    menu.append(Some("About Bobby"), Some("app.about"));
+
+    let file_section = Menu::new();
+
+    if let Some(recent) = recent_menu(app.upcast_ref()) {
+        file_section.append_submenu(Some("Recent Files"), &recent);
+    }
+
+    let table_section = Menu::new();
+    table_section.append(Some("Export Table…"), Some("win.export"));
+    table_section.append(Some("Export Database…"), Some("win.export-database"));
+
+    let view_section = Menu::new();
+    view_section.append(Some("Command Palette…"), Some("win.palette"));
+    view_section.append(Some("Show Sidebar"), Some("win.sidebar"));
+
+    let app_section = Menu::new();
+    app_section.append(Some("Preferences"), Some("app.preferences"));
+    app_section.append(Some("Keyboard Shortcuts"), Some("app.shortcuts"));
+    app_section.append(Some("About Bobby"), Some("app.about"));
+
+    menu.append_section(None, &file_section);
+    menu.append_section(None, &table_section);
+    menu.append_section(None, &view_section);
+    menu.append_section(None, &app_section);
 
     button.set_popover(
         Some(&PopoverMenu::from_model(Some(&menu)))
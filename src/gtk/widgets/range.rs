@@ -0,0 +1,314 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gtk4::prelude::*;
+use gtk4::{
+    gdk::BUTTON_PRIMARY,
+    gdk::Key,
+    gdk::ModifierType,
+    glib::Propagation,
+    ColumnView,
+    EventControllerKey,
+    EventSequenceState,
+    GestureDrag,
+    Label,
+    ListScrollFlags,
+    PickFlags,
+    PropagationPhase,
+    Widget,
+};
+
+use crate::bobby::prelude::*;
+use crate::gtk::view::{ column_index, view_column, view_columns };
+
+
+/// Shared between the column view and the item factories, which highlight the cells in range
+pub type SharedCellRange = Rc<RefCell<Option<CellRange>>>;
+
+/// The column index of each column shown, in the order they're shown in, as cell
+/// ranges go by what's on screen. Also shared with the item factories.
+pub type SharedColumnOrder = Rc<RefCell<Vec<usize>>>;
+
+const HIGHLIGHT_CLASS: &str = "accent";
+
+
+/// Lets a block of cells be selected by dragging with Alt held, or with Shift and
+/// the arrow keys. Dragging without Alt is left to the rubberband, which selects rows.
+pub fn cell_range_setup(
+    column_view: &ColumnView,
+    cell_range: &SharedCellRange,
+    order: &SharedColumnOrder,
+) {
+    // SAFETY: Only ever read back as SharedCellRange and SharedColumnOrder
+    unsafe {
+        column_view.set_data("cell-range", cell_range.clone());
+        column_view.set_data("column-order", order.clone());
+    }
+
+    column_order_setup(column_view, order);
+
+    // Sees the press before the rubberband does, so that it can take it over
+    let drag = GestureDrag::builder()
+        .button(BUTTON_PRIMARY)
+        .propagation_phase(PropagationPhase::Capture)
+        .build();
+
+    let column_view_handle = column_view.clone();
+    let cell_range_handle = cell_range.clone();
+
+    drag.connect_drag_begin(move |drag, x, y| {
+        let alt = drag.current_event_state().contains(ModifierType::ALT_MASK);
+        let range = CellRange::pressed(cell_at(&column_view_handle, x, y), alt);
+
+        drag.set_state(match range {
+            Some(_) => EventSequenceState::Claimed,
+            None => EventSequenceState::Denied,
+        });
+
+        // Also drops the highlight of a range that a plain click replaces
+        if cell_range_handle.replace(range).is_some() {
+            cell_range_refresh(&column_view_handle, range);
+        }
+    });
+
+    let column_view_handle = column_view.clone();
+    let cell_range_handle = cell_range.clone();
+
+    drag.connect_drag_update(move |drag, offset_x, offset_y| {
+        let Some((start_x, start_y)) = drag.start_point() else {
+            return;
+        };
+
+        let Some(cursor) = cell_at(&column_view_handle, start_x + offset_x, start_y + offset_y) else {
+            return;
+        };
+
+        let changed = match cell_range_handle.borrow_mut().as_mut() {
+            Some(range) if range.cursor != cursor => {
+                range.cursor = cursor;
+                true
+            },
+            _ => false,
+        };
+
+        if changed {
+            cell_range_changed(&column_view_handle, &cell_range_handle);
+        }
+    });
+
+    let column_view_handle = column_view.clone();
+    let cell_range_handle = cell_range.clone();
+
+    // A plain click leaves the row selection to the column view
+    drag.connect_drag_end(move |_, _, _| {
+        if cell_range_handle.borrow().is_some_and(|range| range.is_single()) {
+            cell_range_handle.replace(None);
+            cell_range_refresh(&column_view_handle, None);
+        }
+    });
+
+    column_view.add_controller(drag);
+
+
+    let keys = EventControllerKey::new();
+
+    let column_view_handle = column_view.clone();
+    let cell_range_handle = cell_range.clone();
+
+    keys.connect_key_pressed(move |_, key, _, modifiers| {
+        let step = match key {
+            Key::Up    => (-1, 0),
+            Key::Down  => (1, 0),
+            Key::Left  => (0, -1),
+            Key::Right => (0, 1),
+            _ => return Propagation::Proceed,
+        };
+
+        if !modifiers.contains(ModifierType::SHIFT_MASK) {
+            if cell_range_handle.replace(None).is_some() {
+                cell_range_refresh(&column_view_handle, None);
+            }
+
+            return Propagation::Proceed;
+        }
+
+        let n_rows = column_view_handle.model().map(|m| m.n_items()).unwrap_or(0);
+        let n_columns = column_order(&column_view_handle).len();
+
+        let start = cell_range_handle.borrow().or_else(|| focused_cell(&column_view_handle));
+
+        let Some(mut range) = start else {
+            return Propagation::Proceed;
+        };
+
+        range.extend(step.0, step.1, n_rows, n_columns);
+        cell_range_handle.replace(Some(range));
+        cell_range_changed(&column_view_handle, &cell_range_handle);
+
+        let column = column_order(&column_view_handle)
+            .get(range.cursor.1)
+            .and_then(|index| view_column(&column_view_handle, index + 1));
+
+        column_view_handle.scroll_to(range.cursor.0, column.as_ref(), ListScrollFlags::NONE, None);
+
+        Propagation::Stop
+    });
+
+    column_view.add_controller(keys);
+}
+
+
+/// Keeps `order` up to date as columns are moved, hidden and shown
+fn column_order_setup(column_view: &ColumnView, order: &SharedColumnOrder) {
+    let update = Rc::new({
+        let column_view_weak = column_view.downgrade();
+        let shared_order = order.clone();
+
+        move || {
+            if let Some(column_view) = column_view_weak.upgrade() {
+                let order = view_columns(&column_view)
+                    .iter()
+                    .filter(|column| column.is_visible())
+                    .filter_map(column_index)
+                    .filter_map(|index| index.checked_sub(1)) // Row numbers
+                    .collect();
+
+                shared_order.replace(order);
+            }
+        }
+    });
+
+    update();
+
+    for column in view_columns(column_view) {
+        let update = update.clone();
+        column.connect_visible_notify(move |_| update());
+    }
+
+    column_view.columns().connect_items_changed(move |_, _, _, _| update());
+}
+
+
+/// The column index of each column shown, in the order they're shown in
+pub fn column_order(column_view: &ColumnView) -> Vec<usize> {
+    // SAFETY: Set as SharedColumnOrder in cell_range_setup()
+    unsafe {
+        column_view
+            .data::<SharedColumnOrder>("column-order")
+            .map(|order| order.as_ref().borrow().clone())
+            .unwrap_or_default()
+    }
+}
+
+
+/// The cells selected in `column_view`, if more than one
+pub fn cell_range(column_view: &ColumnView) -> Option<CellRange> {
+    // SAFETY: Set as SharedCellRange in cell_range_setup()
+    let range = unsafe {
+        column_view
+            .data::<SharedCellRange>("cell-range")
+            .and_then(|range| *range.as_ref().borrow())
+    };
+
+    range.filter(|range| !range.is_single())
+}
+
+
+/// Selects the rows in range and highlights its cells
+fn cell_range_changed(column_view: &ColumnView, cell_range: &SharedCellRange) {
+    let range = *cell_range.borrow();
+
+    if let Some(range) = range &&
+       let Some(selection) = column_view.model()
+    {
+        let rows = range.rows();
+        selection.select_range(*rows.start(), rows.end() - rows.start() + 1, true);
+    }
+
+    cell_range_refresh(column_view, range);
+}
+
+
+/// Updates the highlight of the cells that are currently shown
+fn cell_range_refresh(column_view: &ColumnView, range: Option<CellRange>) {
+    fn walk(widget: &Widget, range: Option<CellRange>, order: &[usize]) {
+        if let Some(label) = widget.downcast_ref::<Label>() {
+            if let Some((row, column)) = label_cell(label, order) {
+                cell_highlight(label, range.is_some_and(|r| !r.is_single() && r.contains(row, column)));
+            }
+
+            return;
+        }
+
+        let mut child = widget.first_child();
+
+        while let Some(widget) = child {
+            walk(&widget, range, order);
+            child = widget.next_sibling();
+        }
+    }
+
+    walk(column_view.upcast_ref(), range, &column_order(column_view));
+}
+
+
+pub fn cell_highlight(label: &Label, highlighted: bool) {
+    if highlighted {
+        label.add_css_class(HIGHLIGHT_CLASS);
+    } else {
+        label.remove_css_class(HIGHLIGHT_CLASS);
+    }
+}
+
+
+/// The row position and column position of the cell under `x`, `y`
+fn cell_at(column_view: &ColumnView, x: f64, y: f64) -> Option<(u32, usize)> {
+    let picked = column_view.pick(x, y, PickFlags::NON_TARGETABLE)?;
+
+    let label = match picked.downcast::<Label>() {
+        Ok(label) => label,
+        Err(widget) => widget.first_child().and_downcast::<Label>()?, // Cell margin
+    };
+
+    label_cell(&label, &column_order(column_view))
+}
+
+
+/// Starts from the cell with keyboard focus, as moved by Tab
+fn focused_cell(column_view: &ColumnView) -> Option<CellRange> {
+    let focus = column_view.root()?.focus()?;
+
+    let label = match focus.downcast::<Label>() {
+        Ok(label) => label,
+        Err(widget) => widget.first_child().and_downcast::<Label>()?,
+    };
+
+    label_cell(&label, &column_order(column_view)).map(|(row, column)| CellRange::new(row, column))
+}
+
+
+/// The row position and column position of the cell `label` shows
+fn label_cell(label: &Label, order: &[usize]) -> Option<(u32, usize)> {
+    let index = label
+        .widget_name()
+        .parse::<usize>().ok()?
+        .checked_sub(1)?; // Row numbers
+
+    let column = order.iter().position(|i| *i == index)?;
+
+    // SAFETY: Set as u32 when the item was bound
+    let row = unsafe {
+        label
+            .data::<u32>("position")
+            .map(|position| *position.as_ref())?
+    };
+
+    Some((row, column))
+}
@@ -0,0 +1,129 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    Align,
+    Application,
+    Button,
+    ListBox,
+    SelectionMode,
+};
+
+use libadwaita::prelude::*;
+use libadwaita::{
+    ActionRow,
+    Clamp,
+};
+
+use crate::bobby::recent::RecentFile;
+use crate::gtk::recent::{ recent_file_title, recent_files, recent_menu };
+
+
+/// Recently opened files for the start page, which keeps up with
+/// files being opened and removed in any window
+pub fn recent_list_new(app: &Application) -> Clamp {
+    let list = ListBox::builder()
+        .selection_mode(SelectionMode::None)
+        .css_classes(["boxed-list"])
+        .build();
+
+    let clamp = Clamp::builder()
+        .maximum_size(480)
+        .margin_top(24)
+        .child(&list)
+        .build();
+
+    recent_list_update(&list, app);
+
+    if let Some(menu) = recent_menu(app) {
+        let list_weak = list.downgrade();
+        let app_weak = app.downgrade();
+
+        menu.connect_items_changed(move |_, position, _, added| {
+            if position == 0 && added > 0 &&
+               let Some(list) = list_weak.upgrade() &&
+               let Some(app) = app_weak.upgrade()
+            {
+                recent_list_update(&list, &app);
+            }
+        });
+    }
+
+    clamp
+}
+
+
+fn recent_list_update(list: &ListBox, app: &Application) {
+    let recent = recent_files(app);
+
+    list.remove_all();
+    list.set_visible(!recent.files().is_empty());
+
+    for file in recent.files() {
+        list.append(&recent_row_new(file));
+    }
+}
+
+
+fn recent_row_new(file: &RecentFile) -> ActionRow {
+    let path = file.path.to_string_lossy().to_string();
+    let exists = file.exists();
+
+    let row = ActionRow::builder()
+        .title(recent_file_title(file))
+        .subtitle(recent_file_subtitle(file, exists))
+        .use_markup(false)
+        .activatable(exists)
+        .build();
+
+    if exists {
+        row.set_action_name(Some("app.open-recent"));
+        row.set_action_target_value(Some(&path.to_variant()));
+    } else {
+        row.add_css_class("dim-label");
+    }
+
+    let remove = Button::builder()
+        .icon_name("window-close-symbolic")
+        .tooltip_text("Remove from Recent Files")
+        .valign(Align::Center)
+        .css_classes(["flat", "circular"])
+        .action_name("app.remove-recent")
+        .action_target(&path.to_variant())
+        .build();
+
+    row.add_suffix(&remove);
+    row
+}
+
+
+/// Like "~/Projects · 1.2 MB · 14 March 2025"
+fn recent_file_subtitle(file: &RecentFile, exists: bool) -> String {
+    let folder = file.path
+        .parent()
+        .map(|dir| {
+            let dir = dir.to_string_lossy().to_string();
+
+            match glib::home_dir().to_str() {
+                Some(home) if dir.starts_with(home) => dir.replacen(home, "~", 1),
+                _ => dir,
+            }
+        })
+        .unwrap_or_default();
+
+    if !exists {
+        return format!("{folder} · Missing");
+    }
+
+    let opened = chrono::DateTime::from_timestamp(file.opened, 0)
+        .map(|time| time.with_timezone(&chrono::Local).format("%-d %B %Y").to_string())
+        .unwrap_or_default();
+
+    format!("{folder} · {} · {opened}", glib::format_size(file.size))
+}
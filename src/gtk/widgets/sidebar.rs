@@ -0,0 +1,264 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::Cell;
+use std::sync::mpsc;
+use std::sync::mpsc::TryRecvError;
+use std::time::Duration;
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    glib::ControlFlow,
+    glib::WeakRef,
+    Align,
+    Image,
+    Label,
+    ListBox,
+    ListBoxRow,
+    Orientation,
+    ScrolledWindow,
+    SearchEntry,
+    SelectionMode,
+};
+
+use libadwaita::{
+    HeaderBar,
+    ToolbarView,
+};
+
+use crate::log;
+use crate::bobby::prelude::*;
+use crate::gtk::cache::cache_snapshots;
+
+
+const WIDGET_NAME: &str = "sidebar";
+
+/// How often counts from the worker thread are picked up
+const COUNT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Sections in the order they're listed in
+const KINDS: [(&str, &str); 3] = [
+    ("Tables", "view-grid-symbolic"),
+    ("Views", "view-reveal-symbolic"),
+    ("Virtual Tables", "application-x-addon-symbolic"),
+];
+
+
+/// Lists every table, grouped by kind, with its row count.
+/// Selecting one switches to it through the win.table action.
+pub fn sidebar_new(database: &Database, tables: &[Table]) -> ToolbarView {
+    let list = ListBox::builder()
+        .selection_mode(SelectionMode::Single)
+        .css_classes(["navigation-sidebar"])
+        .build();
+
+    list.set_widget_name(WIDGET_NAME);
+
+    let kinds = tables.iter().map(table_kind).collect::<Vec<_>>();
+    let names = tables.iter().map(|t| t.name().to_lowercase()).collect::<Vec<_>>();
+
+    let mut order = (0..tables.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| kinds[i]); // Stable, so still by name within a kind
+
+    let mut labels = Vec::with_capacity(tables.len());
+    let mut counted = Vec::with_capacity(tables.len());
+
+    for i in order {
+        let (row, count) = sidebar_row_new(&tables[i], i, kinds[i]);
+        list.append(&row);
+
+        labels.push(count.downgrade());
+        counted.push(tables[i].clone());
+    }
+
+    let counts = (labels, counted);
+
+
+    let search_entry = SearchEntry::builder()
+        .placeholder_text("Filter Tables")
+        .margin_start(6)
+        .margin_end(6)
+        .margin_bottom(6)
+        .build();
+
+    search_entry.set_key_capture_widget(Some(&list));
+
+    let search_entry_handle = search_entry.clone();
+
+    list.set_filter_func(move |row| {
+        let query = search_entry_handle.text().to_lowercase();
+
+        row_index(row)
+            .and_then(|i| names.get(i))
+            .is_some_and(|name| name.contains(&query))
+    });
+
+    // Headers go above the first visible row of each kind
+    list.set_header_func(move |row, before| {
+        let kind = row_index(row).and_then(|i| kinds.get(i));
+        let previous = before.and_then(row_index).and_then(|i| kinds.get(i));
+
+        match kind {
+            Some(&kind) if kind != previous.copied().unwrap_or(usize::MAX) => {
+                let header = Label::builder()
+                    .label(KINDS[kind].0)
+                    .halign(Align::Start)
+                    .margin_start(12)
+                    .margin_top(12)
+                    .margin_bottom(6)
+                    .css_classes(["heading", "dim-label"])
+                    .build();
+
+                row.set_header(Some(&header));
+            },
+            _ => row.set_header(None::<&gtk4::Widget>),
+        }
+    });
+
+    let list_handle = list.clone();
+
+    search_entry.connect_search_changed(move |_| {
+        list_handle.invalidate_filter();
+        list_handle.invalidate_headers();
+    });
+
+
+    // Counting can take a while on big tables, so only start once the sidebar
+    // is shown, on its own connection as the window's can't be shared across threads
+    let file = database.file.clone();
+    let counts = Cell::new(Some(counts));
+
+    list.connect_map(move |_| {
+        let Some((labels, tables)) = counts.take() else {
+            return;
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let file = file.clone();
+        let snapshots = cache_snapshots();
+
+        gio::spawn_blocking(move || {
+            let database = match Database::from_file(&file, None, &snapshots) {
+                Ok(database) => database,
+                Err(e) => return log::error(&e.to_string()),
+            };
+
+            for (i, table) in tables.iter().enumerate() {
+                let count = database.row_count(table).map_err(|e| e.to_string());
+
+                // The sidebar is gone when it was rebuilt on reload
+                if sender.send((i, count)).is_err() {
+                    return;
+                }
+            }
+        });
+
+        glib::timeout_add_local(COUNT_INTERVAL, move || {
+            loop {
+                let (i, count) = match receiver.try_recv() {
+                    Ok(received) => received,
+                    Err(TryRecvError::Empty) => return ControlFlow::Continue,
+                    Err(TryRecvError::Disconnected) => return ControlFlow::Break,
+                };
+
+                let Some(label) = labels.get(i).and_then(WeakRef::upgrade) else {
+                    return ControlFlow::Break;
+                };
+
+                match count {
+                    Ok(count) => label.set_label(&count.to_string()),
+                    Err(e) => label.set_tooltip_text(Some(&e)),
+                }
+            }
+        });
+    });
+
+
+    let scrolled_window = ScrolledWindow::builder()
+        .hscrollbar_policy(gtk4::PolicyType::Never)
+        .vexpand(true)
+        .child(&list)
+        .build();
+
+    // Shows the title of the sidebar page
+    let header = HeaderBar::new();
+
+    let toolbar_view = ToolbarView::new();
+    toolbar_view.add_top_bar(&header);
+    toolbar_view.add_top_bar(&search_entry);
+    toolbar_view.set_content(Some(&scrolled_window));
+
+    toolbar_view
+}
+
+
+/// Highlights the row of the table at `table_index` in the win.table action
+pub fn sidebar_select(list: &ListBox, table_index: &str) {
+    let mut child = list.first_child();
+
+    while let Some(widget) = child {
+        if let Some(row) = widget.downcast_ref::<ListBoxRow>() &&
+           row.action_target_value().and_then(|v| v.str().map(String::from)).as_deref() == Some(table_index)
+        {
+            list.select_row(Some(row));
+            return;
+        }
+
+        child = widget.next_sibling();
+    }
+
+    list.unselect_all();
+}
+
+
+fn sidebar_row_new(table: &Table, index: usize, kind: usize) -> (ListBoxRow, Label) {
+    let icon = Image::from_icon_name(KINDS[kind].1);
+
+    let name = Label::builder()
+        .label(table.name())
+        .halign(Align::Start)
+        .hexpand(true)
+        .ellipsize(gtk4::pango::EllipsizeMode::End)
+        .build();
+
+    let count = Label::builder()
+        .css_classes(["dim-label", "numeric"])
+        .build();
+
+    let layout = gtk4::Box::new(Orientation::Horizontal, 12);
+    layout.append(&icon);
+    layout.append(&name);
+    layout.append(&count);
+
+    let row = ListBoxRow::builder()
+        .child(&layout)
+        .tooltip_text(table.name())
+        .build();
+
+    row.set_action_name(Some("win.table"));
+    row.set_action_target_value(Some(&index.to_string().to_variant()));
+
+    (row, count)
+}
+
+
+fn table_kind(table: &Table) -> usize {
+    match (table.is_view(), table.is_virtual()) {
+        (true, _) => 1,
+        (_, true) => 2,
+        _ => 0,
+    }
+}
+
+
+fn row_index(row: &ListBoxRow) -> Option<usize> {
+    row.action_target_value()?
+        .str()?
+        .parse()
+        .ok()
+}
@@ -0,0 +1,87 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use gtk4::glib::Propagation;
+use gtk4::ScrolledWindow;
+
+use libadwaita::prelude::*;
+use libadwaita::{
+    ApplicationWindow,
+    TabBar,
+    TabPage,
+    TabView,
+};
+
+use crate::bobby::prelude::*;
+
+
+const WIDGET_NAME: &str = "tabs";
+
+/// Holds a tab for each open table. Ctrl+Tab, Ctrl+Page Up/Down
+/// and Alt+number switch between them, as set up by TabView
+pub fn tab_view_new(window: &ApplicationWindow) -> TabView {
+    let tab_view = TabView::builder()
+        .vexpand(true)
+        .build();
+
+    tab_view.set_widget_name(WIDGET_NAME);
+
+    let window_weak = window.downgrade();
+
+    // Closing the last tab closes the window, like in other GNOME apps
+    tab_view.connect_close_page(move |tab_view, page| {
+        if tab_view.n_pages() > 1 {
+            return Propagation::Proceed;
+        }
+
+        // Stopping leaves the close pending until it's finished
+        tab_view.close_page_finish(page, false);
+
+        if let Some(window) = window_weak.upgrade() {
+            window.close();
+        }
+
+        Propagation::Stop
+    });
+
+    tab_view
+}
+
+
+/// Only shown when there's more than one tab
+pub fn tab_bar_new(tab_view: &TabView) -> TabBar {
+    TabBar::builder()
+        .view(tab_view)
+        .autohide(true)
+        .build()
+}
+
+
+pub fn tab_page_add(tab_view: &TabView, table: &Table, content: &ScrolledWindow) -> TabPage {
+    let page = tab_view.append(content);
+
+    page.set_title(&table.name());
+    page.set_keyword(&table.name());
+
+    page
+}
+
+
+/// The tab showing the table named `table_name`, if it's open
+pub fn tab_page_find(tab_view: &TabView, table_name: &str) -> Option<TabPage> {
+    tab_pages(tab_view)
+        .into_iter()
+        .find(|page| page.keyword().is_some_and(|keyword| keyword == table_name))
+}
+
+
+/// All tabs, from left to right
+pub fn tab_pages(tab_view: &TabView) -> Vec<TabPage> {
+    (0..tab_view.n_pages())
+        .map(|i| tab_view.nth_page(i))
+        .collect()
+}
@@ -0,0 +1,239 @@
+//   Bobby, browse SQLite files
+//   Copyright (C) 2025  Hylke Bons (hello@planetpeanut.studio)
+//
+//   This program is free software: you can redistribute it and/or modify it
+//   under the terms of the GNU General Public License v3 or any later version.
+
+
+use std::cell::{ Cell, Ref, RefCell };
+use std::error::Error;
+use std::rc::Rc;
+
+use gio::SimpleAction;
+
+use gtk4::prelude::*;
+use gtk4::{
+    glib,
+    glib::BoxedAnyObject,
+    ListScrollFlags,
+    ScrolledWindow,
+    Window,
+};
+
+use libadwaita::TabPage;
+
+use crate::bobby::history::History;
+use crate::bobby::prelude::*;
+use crate::gtk::util::{
+    find_cache_model,
+    find_column_view,
+    find_tab_view,
+    first_selected_row,
+};
+
+
+/// Where someone was looking: the table, the selected row and how far it was scrolled
+#[derive(Clone, Debug, PartialEq)]
+pub struct Place {
+    table_name: String,
+    rowid: Option<i64>,
+    position: Option<u32>,
+    h_value: f64,
+    v_value: f64,
+}
+
+
+/// Kept on the window, so it lasts across reloads
+#[derive(Default)]
+pub struct WindowHistory {
+    history: RefCell<History<Place>>,
+
+    /// Set while going back or forward, which isn't a visit itself
+    navigating: Cell<bool>,
+}
+
+pub type SharedHistory = Rc<WindowHistory>;
+
+
+pub fn window_history(window: &Window) -> SharedHistory {
+    // SAFETY: Only ever set as SharedHistory
+    let history = unsafe {
+        window
+            .data::<SharedHistory>("history")
+            .map(|history| history.as_ref().clone())
+    };
+
+    match history {
+        Some(history) => history,
+        None => {
+            let history = SharedHistory::default();
+
+            // SAFETY: Read back as SharedHistory above
+            unsafe {
+                window.set_data("history", history.clone());
+            }
+
+            history
+        },
+    }
+}
+
+
+/// Remembers the place in the selected tab before going elsewhere
+pub fn window_history_record(window: &Window) {
+    let place = find_tab_view(window.upcast_ref())
+        .and_then(|tab_view| tab_view.selected_page())
+        .and_then(|page| tab_place(&page));
+
+    if let Some(place) = place {
+        window_history_visit(window, place);
+    }
+}
+
+
+/// Remembers `place` as visited, unless going back or forward
+pub fn window_history_visit(window: &Window, place: Place) {
+    let history = window_history(window);
+
+    if history.navigating.get() {
+        return;
+    }
+
+    history.history.borrow_mut().visit(place);
+    window_history_changed(window);
+}
+
+
+pub fn window_go_back(window: &Window) -> Result<(), Box<dyn Error>> {
+    window_go(window, |history, from| history.back(from))
+}
+
+pub fn window_go_forward(window: &Window) -> Result<(), Box<dyn Error>> {
+    window_go(window, |history, from| history.forward(from))
+}
+
+
+fn window_go(
+    window: &Window,
+    step: impl FnOnce(&mut History<Place>, Place) -> Option<Place>,
+) -> Result<(), Box<dyn Error>>
+{
+    let page = find_tab_view(window.upcast_ref())
+        .and_then(|tab_view| tab_view.selected_page())
+        .ok_or("Missing selected tab")?;
+
+    let from = tab_place(&page).ok_or("Missing content")?;
+    let history = window_history(window);
+
+    let Some(to) = step(&mut history.history.borrow_mut(), from) else {
+        return Ok(());
+    };
+
+    let result = window_history_skip(window, || window_show_place(window, &to));
+
+    window_history_changed(window);
+    result
+}
+
+
+/// Runs `f` without recording the places it goes to, as
+/// they're part of a visit that's already been recorded
+pub fn window_history_skip<T>(window: &Window, f: impl FnOnce() -> T) -> T {
+    let history = window_history(window);
+    let navigating = history.navigating.replace(true);
+
+    let result = f();
+    history.navigating.set(navigating);
+
+    result
+}
+
+
+fn window_show_place(window: &Window, place: &Place) -> Result<(), Box<dyn Error>> {
+    let model = find_cache_model(window.upcast_ref()).ok_or("Missing content")?;
+    let (database, _) = model.database_and_table();
+
+    let index = database.tables()?
+        .iter()
+        .position(|t| t.name() == place.table_name)
+        .ok_or(format!("No table named ‘{}’", place.table_name))?;
+
+    WidgetExt::activate_action(window, "win.table", Some(&index.to_string().to_variant()))?;
+
+    let page = find_tab_view(window.upcast_ref())
+        .and_then(|tab_view| tab_view.selected_page())
+        .ok_or("Missing selected tab")?;
+
+    let scrolled_window = page.child().downcast::<ScrolledWindow>()
+        .map_err(|w| format!("Expected ScrolledWindow, but got {}", w.type_().name()))?;
+
+    let column_view = find_column_view(scrolled_window.upcast_ref()).ok_or("Missing content")?;
+    let model = find_cache_model(scrolled_window.upcast_ref()).ok_or("Missing content")?;
+    let (database, table) = model.database_and_table();
+
+    // Rowids stay put when rows are added, positions don't
+    let position = match place.rowid {
+        Some(rowid) => database.rowid_position(&table, rowid)?,
+        None => place.position,
+    };
+
+    if let Some(position) = position.filter(|p| *p < model.n_items()) {
+        column_view.scroll_to(position, None, ListScrollFlags::FOCUS | ListScrollFlags::SELECT, None);
+    }
+
+    let (h_value, v_value) = (place.h_value, place.v_value);
+
+    glib::idle_add_local_once(move || {
+        scrolled_window.hadjustment().set_value(h_value);
+        scrolled_window.vadjustment().set_value(v_value);
+    });
+
+    Ok(())
+}
+
+
+/// The place shown in the tab of `page`
+pub fn tab_place(page: &TabPage) -> Option<Place> {
+    let scrolled_window = page.child().downcast::<ScrolledWindow>().ok()?;
+    let column_view = find_column_view(scrolled_window.upcast_ref())?;
+
+    let position = first_selected_row(&column_view);
+
+    let rowid = position
+        .and_then(|position| column_view.model()?.item(position))
+        .and_downcast::<BoxedAnyObject>()
+        .and_then(|item| {
+            let row: Ref<Row> = item.borrow();
+            row.rowid
+        });
+
+    Some(Place {
+        table_name: page.keyword()?.into(),
+        rowid,
+        position,
+        h_value: scrolled_window.hadjustment().value(),
+        v_value: scrolled_window.vadjustment().value(),
+    })
+}
+
+
+/// Enables the back and forward actions when there's somewhere to go
+fn window_history_changed(window: &Window) {
+    let history = window_history(window);
+    let history = history.history.borrow();
+
+    let actions = [
+        ("back", history.can_go_back()),
+        ("forward", history.can_go_forward()),
+    ];
+
+    for (name, enabled) in actions {
+        if let Some(action) = window
+            .dynamic_cast_ref::<gio::ActionMap>()
+            .and_then(|map| map.lookup_action(name))
+            .and_downcast::<SimpleAction>()
+        {
+            action.set_enabled(enabled);
+        }
+    }
+}
@@ -6,5 +6,9 @@
 
 
 pub use crate::gtk::windows::window::window_handle_open;
+pub use crate::gtk::windows::window::window_jump_to_row;
 pub use crate::gtk::windows::window::window_new;
+pub use crate::gtk::windows::window::window_restore_session;
+pub use crate::gtk::windows::window::window_session;
+pub use crate::gtk::windows::window::window_show_location;
 pub use crate::gtk::windows::window::IS_EMPTY_WINDOW;
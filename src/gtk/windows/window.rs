@@ -5,13 +5,24 @@
 //   under the terms of the GNU General Public License v3 or any later version.
 
 
+use std::cell::Cell;
 use std::error::Error;
+use std::path::Path;
+use std::rc::Rc;
 
-use gio::File;
+use gio::{ File, SimpleAction };
 
 use gtk4::{
+    Align,
+    Bitset,
+    glib::Propagation,
+    glib::Variant,
+    ListBox,
+    ListScrollFlags,
     MenuButton,
+    MultiSelection,
     Orientation,
+    RecentManager,
     ScrolledWindow,
     Widget,
     Window,
@@ -22,27 +33,64 @@ use libadwaita::{
     Application,
     ApplicationWindow,
     HeaderBar,
+    NavigationPage,
+    NavigationSplitView,
     StatusPage,
+    TabPage,
+    Toast,
     ToastOverlay,
     ToolbarStyle,
     ToolbarView,
 };
 
 use crate::bobby::prelude::*;
+use crate::bobby::session::{ TabSession, WindowSession };
+use crate::bobby::sqlite::cache::DatabaseCacheModel;
+use crate::bobby::Error as DatabaseError;
+use crate::log;
 
 use crate::gtk::actions::prelude::*;
-use crate::gtk::util::widget_by_name;
-use crate::gtk::widgets::button::button_open_new;
+use crate::gtk::cache::cache_snapshots;
+use crate::gtk::monitor::monitor_database;
+use crate::gtk::recent::{ recent_add, recent_table };
+use crate::gtk::session::session_save;
+use crate::gtk::view::view_column;
+use crate::gtk::util::{
+    find_cache_model,
+    find_column_view,
+    find_tab_view,
+    first_selected_row,
+    selected_runs,
+    widget_by_name,
+};
+use crate::gtk::widgets::button::{
+    button_follow_new,
+    button_navigation_new,
+    button_open_new,
+    button_recovery_new,
+};
+use crate::gtk::widgets::columns::button_columns_new;
 use crate::gtk::widgets::content::{ content_new, content_force_redraw };
 use crate::gtk::widgets::drop_target::drop_target_new;
+use crate::gtk::widgets::jump::button_jump_new;
 use crate::gtk::widgets::menu::main_menu_new;
+use crate::gtk::widgets::recent::recent_list_new;
+use crate::gtk::widgets::sidebar::{ sidebar_new, sidebar_select };
 use crate::gtk::widgets::switcher::table_switcher_new;
+use crate::gtk::windows::history::{ window_history_record, window_history_skip };
+use crate::gtk::widgets::tabs::{
+    tab_bar_new,
+    tab_page_add,
+    tab_page_find,
+    tab_pages,
+    tab_view_new,
+};
 
 
 pub fn window_handle_open(
     application: &Application,
     file: &File,
-    table_name: Option<String>,
+    target: &Location,
 ) -> Result<(), Box<dyn Error>>
 {
     let path = file
@@ -51,6 +99,14 @@ pub fn window_handle_open(
         .to_string_lossy()
         .to_string();
 
+    // Also lists it in other apps' recent files
+    RecentManager::default().add_item(&file.uri());
+
+    // Back to where it was left, unless asked for another table
+    let table_name = target.table_name
+        .clone()
+        .or_else(|| recent_table(application.upcast_ref(), Path::new(&path)));
+
     let window = application
         .windows()
         .iter()
@@ -58,25 +114,186 @@ pub fn window_handle_open(
         .cloned();
 
     if let Some(w) = window {
+        window_recent_add(&w);
         w.present();
-        return Ok(());
+        return window_show_location(&w, target);
     }
 
     for window in application.windows() {
         if window.widget_name() == IS_EMPTY_WINDOW {
-            match Database::from_file(file, row_order_from_settings()) {
-                Ok(db) => window_show_content_state(&window, &db, table_name.clone())?,
+            match Database::from_file(file, row_order_from_settings(), &cache_snapshots()) {
+                Ok(db) => {
+                    window_show_content_state(&window, &db, table_name)?;
+                    window_recent_add(&window);
+                },
                 Err(e) => window_show_error_state(&window, file, e)?,
             }
 
             window.present();
-            return Ok(());
+            return window_show_location(&window, target);
         }
     }
 
-    let window = window_new(application, Some(file), table_name.clone())?;
+    let window = window_new(application, Some(file), table_name)?;
     window.present();
 
+    window_show_location(window.upcast_ref(), target)
+}
+
+
+/// Switches to the table and selects the row and column of `target`, where given
+pub fn window_show_location(
+    window: &Window,
+    target: &Location,
+) -> Result<(), Box<dyn Error>>
+{
+    // Nothing to show if the file couldn't be opened
+    let Some(model) = find_cache_model(window.upcast_ref()) else {
+        return Ok(());
+    };
+
+    // One visit, however many tables and rows it passes through
+    window_history_record(window);
+    window_history_skip(window, || window_show_target(window, &model, target))
+}
+
+
+fn window_show_target(
+    window: &Window,
+    model: &DatabaseCacheModel,
+    target: &Location,
+) -> Result<(), Box<dyn Error>>
+{
+    let (database, table) = model.database_and_table();
+
+    if let Some(name) = &target.table_name &&
+       *name != table.name()
+    {
+        let index = database.tables()?
+            .iter()
+            .position(|t| t.name() == *name);
+
+        match index {
+            Some(index) => WidgetExt::activate_action(
+                window,
+                "win.table",
+                Some(&index.to_string().to_variant()),
+            )?,
+            None => window_toast(window, &format!("No table named ‘{name}’"))?,
+        }
+    }
+
+    match (target.rowid, &target.column) {
+        (Some(rowid), column) => window_select_rowid(window, rowid, column.as_deref())?,
+        (None, Some(column)) => window_show_column(window, column)?,
+        (None, None) => {},
+    }
+
+    Ok(())
+}
+
+
+/// Scrolls sideways to `column`, keeping to the selected row
+fn window_show_column(window: &Window, column: &str) -> Result<(), Box<dyn Error>> {
+    let model = find_cache_model(window.upcast_ref()).ok_or("Missing content")?;
+    let column_view = find_column_view(window.upcast_ref()).ok_or("Missing content")?;
+    let (database, table) = model.database_and_table();
+
+    // Views have the row numbers first
+    let view_column = database.columns(&table)?
+        .iter()
+        .position(|c| c.name == column)
+        .and_then(|index| view_column(&column_view, index + 1))
+        .ok_or(format!("No column named ‘{column}’"))?;
+
+    view_column.set_visible(true);
+
+    if model.n_items() == 0 {
+        return Ok(());
+    }
+
+    let position = first_selected_row(&column_view).unwrap_or(0);
+    column_view.scroll_to(position, Some(&view_column), ListScrollFlags::FOCUS, None);
+
+    Ok(())
+}
+
+
+/// Scrolls to the row with `rowid` in the current table and selects it,
+/// also bringing `column` into view
+pub fn window_select_rowid(
+    window: &Window,
+    rowid: i64,
+    column: Option<&str>,
+) -> Result<(), Box<dyn Error>>
+{
+    let model = find_cache_model(window.upcast_ref()).ok_or("Missing content")?;
+    let column_view = find_column_view(window.upcast_ref()).ok_or("Missing content")?;
+    let (database, table) = model.database_and_table();
+
+    // Views have the row numbers first
+    let view_column = match column {
+        Some(name) => database.columns(&table)?
+            .iter()
+            .position(|c| c.name == name)
+            .and_then(|index| view_column(&column_view, index + 1)),
+        None => None,
+    };
+
+    if let Some(view_column) = &view_column {
+        view_column.set_visible(true);
+    }
+
+    match database.rowid_position(&table, rowid)? {
+        Some(position) => column_view.scroll_to(
+            position,
+            view_column.as_ref(),
+            ListScrollFlags::FOCUS | ListScrollFlags::SELECT,
+            None,
+        ),
+        None => window_toast(window, &format!("No row {rowid} in ‘{}’", table.name()))?,
+    }
+
+    Ok(())
+}
+
+
+/// Scrolls to `target` in the current table and selects it
+pub fn window_jump_to_row(window: &Window, target: &RowTarget) -> Result<(), Box<dyn Error>> {
+    let model = find_cache_model(window.upcast_ref()).ok_or("Missing content")?;
+    let column_view = find_column_view(window.upcast_ref()).ok_or("Missing content")?;
+    let (database, table) = model.database_and_table();
+
+    // Only the page around the row gets loaded
+    match database.row_target_position(&table, target)? {
+        Some(position) => {
+            window_history_record(window);
+            column_view.scroll_to(
+                position,
+                None,
+                ListScrollFlags::FOCUS | ListScrollFlags::SELECT,
+                None,
+            )
+        },
+        None => return Err(format!("No {target} in ‘{}’", table.name()).into()),
+    }
+
+    Ok(())
+}
+
+
+fn window_toast(window: &Window, title: &str) -> Result<(), Box<dyn Error>> {
+    let overlay = widget_by_name("overlay", window.upcast_ref())
+        .and_then(|w| w.downcast::<ToastOverlay>().ok())
+        .ok_or("Missing widget named 'overlay'")?;
+
+    overlay.add_toast(
+        Toast::builder()
+            .title(gtk4::glib::markup_escape_text(title))
+            .timeout(4)
+            .build()
+    );
+
     Ok(())
 }
 
@@ -92,33 +309,198 @@ pub fn window_reload(
         .to_string_lossy()
         .to_string();
 
-    let window = application.active_window().ok_or("Missing active window")?;
+    // Not necessarily the active window when reloading on file changes
+    let window = application
+        .windows()
+        .into_iter()
+        .find(|w| w.widget_name() == path)
+        .ok_or("Missing window for file")?;
 
 
-    let widget = widget_by_name(
-        "switcher", // TODO
-        window.upcast_ref::<Widget>(),
-    ).ok_or("Missing widget named 'switcher'")?;
+    // Remember the open tabs, with their scroll position and selection
+    let tab_view = find_tab_view(window.upcast_ref())
+        .ok_or("Missing widget named 'tabs'")?;
 
-    let switcher = widget.downcast::<MenuButton>()
-        .map_err(|w|
-            format!(
-                "Expected MenuButton, but got {}",
-                w.type_().name()
-            )
-        )?;
+    let table_name: Option<String> = tab_view
+        .selected_page()
+        .and_then(|page| page.keyword())
+        .map(|keyword| keyword.into());
 
-    let table_name: Option<String> = switcher.label()
-        .map(|g| g.into());
+    let states = tab_pages(&tab_view)
+        .iter()
+        .filter_map(tab_state)
+        .collect::<Vec<_>>();
 
 
-    // Remember the scroll position
-    let widget = widget_by_name(
-        "content", // TODO
-        window.upcast_ref::<gtk4::Widget>(),
-    ).ok_or("Missing widget named 'content'")?;
+    let db = match Database::from_file(file, row_order_from_settings(), &cache_snapshots()) {
+        Ok(db) => db,
+        Err(e) => return window_show_error_state(&window, file, e),
+    };
+
+    window_show_content_state(&window, &db, table_name)?;
+    window_restore_tabs(&window, &db, states)
+}
+
+
+/// Reopens the tabs of `states` in the same order, next to the one already shown
+fn window_restore_tabs(
+    window: &Window,
+    db: &Database,
+    states: Vec<TabState>,
+) -> Result<(), Box<dyn Error>>
+{
+    let app_window = window.downcast_ref::<ApplicationWindow>()
+        .ok_or("Could not cast to ApplicationWindow")?;
+
+    let tab_view = find_tab_view(window.upcast_ref())
+        .ok_or("Missing widget named 'tabs'")?;
+
+    let tables = db.tables()?;
+    let mut position = 0;
+
+    for state in states {
+        let page = match tab_page_find(&tab_view, &state.table_name) {
+            Some(page) => page,
+            None => match tables.iter().find(|t| t.name() == state.table_name) {
+                Some(table) => tab_page_add(&tab_view, table, &window_change_content(app_window, table)?),
+                None => continue, // Dropped since
+            },
+        };
+
+        tab_view.reorder_page(&page, position);
+        position += 1;
+
+        window_restore_tab(window, db, &page, state)?;
+    }
+
+    Ok(())
+}
+
+
+/// What to restore the window to when Bobby is started again,
+/// or `None` for windows without a file
+pub fn window_session(window: &Window) -> Option<WindowSession> {
+    let path = window.widget_name();
+
+    if path.is_empty() || path == IS_EMPTY_WINDOW {
+        return None;
+    }
+
+    let tab_view = find_tab_view(window.upcast_ref());
+    let selected_page = tab_view.as_ref().and_then(|tab_view| tab_view.selected_page());
+
+    let tabs = tab_view
+        .map(|tab_view| tab_pages(&tab_view))
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|page| {
+            let scrolled_window = page.child().downcast::<ScrolledWindow>().ok()?;
+            let column_view = find_column_view(scrolled_window.upcast_ref())?;
+
+            Some(TabSession {
+                table_name: page.keyword()?.into(),
+                is_selected: Some(page) == selected_page.as_ref(),
+                h_value: scrolled_window.hadjustment().value(),
+                v_value: scrolled_window.vadjustment().value(),
+                selection: selected_runs(&column_view),
+            })
+        })
+        .collect();
+
+    let (width, height) = window.default_size();
+
+    Some(WindowSession {
+        path: path.into(),
+        width,
+        height,
+        maximized: window.is_maximized(),
+        tabs,
+    })
+}
+
 
-    let scrolled_window = widget.downcast::<gtk4::ScrolledWindow>()
+/// Opens a window as it was in the last session
+pub fn window_restore_session(
+    application: &Application,
+    session: &WindowSession,
+) -> Result<(), Box<dyn Error>>
+{
+    let file = File::for_path(&session.path);
+    let window = window_new(application, Some(&file), session.table_name().map(String::from))?;
+
+    window.set_default_size(session.width, session.height);
+    window.set_maximized(session.maximized);
+    window.present();
+
+    // Nothing more to restore if the file couldn't be opened
+    let Some(model) = find_cache_model(window.upcast_ref()) else {
+        return Ok(());
+    };
+
+    let (db, _) = model.database_and_table();
+
+    let states = session.tabs
+        .iter()
+        .map(|tab| {
+            let selected = Bitset::new_empty();
+
+            for (first, n) in &tab.selection {
+                selected.add_range(*first, *n);
+            }
+
+            TabState {
+                table_name: tab.table_name.clone(),
+                h_value: tab.h_value,
+                v_value: tab.v_value,
+                selected: Some(selected),
+                previous: None,
+            }
+        })
+        .collect();
+
+    window_restore_tabs(window.upcast_ref(), &db, states)
+}
+
+
+/// What's kept of a tab across reloads
+struct TabState {
+    table_name: String,
+    h_value: f64,
+    v_value: f64,
+    selected: Option<Bitset>,
+    previous: Option<Fingerprints>,
+}
+
+
+fn tab_state(page: &TabPage) -> Option<TabState> {
+    let scrolled_window = page.child().downcast::<ScrolledWindow>().ok()?;
+
+    let selection = find_column_view(scrolled_window.upcast_ref())
+        .and_then(|column_view| column_view.model())
+        .and_then(|model| model.downcast::<MultiSelection>().ok());
+
+    Some(TabState {
+        table_name: page.keyword()?.into(),
+        h_value: scrolled_window.hadjustment().value(),
+        v_value: scrolled_window.vadjustment().value(),
+        selected: selection.as_ref().map(|s| s.selection()),
+        previous: selection
+            .and_then(|s| s.model())
+            .and_then(|model| model.downcast::<DatabaseCacheModel>().ok())
+            .and_then(|model| model.fingerprints()),
+    })
+}
+
+
+/// Reapplies the scroll position and selection of a reloaded tab
+fn window_restore_tab(
+    window: &Window,
+    db: &Database,
+    page: &TabPage,
+    state: TabState,
+) -> Result<(), Box<dyn Error>>
+{
+    let scrolled_window = page.child().downcast::<ScrolledWindow>()
         .map_err(|w|
             format!(
                 "Expected ScrolledWindow, but got {}",
@@ -126,43 +508,137 @@ pub fn window_reload(
             )
         )?;
 
-    let h_value = scrolled_window.hadjustment().value();
-    let v_value = scrolled_window.vadjustment().value();
+    if let Some(previous) = state.previous {
+        window_show_changes(window, db, &scrolled_window, previous)?;
+    }
 
-    for window in application.windows() {
-        if window.widget_name() == path {
-            match Database::from_file(file, row_order_from_settings()) {
-                Ok(db) => window_show_content_state(&window, &db, table_name)?,
-                Err(e) => window_show_error_state(&window, file, e)?,
-            }
+    if let Some(selected) = state.selected &&
+       let Some(column_view) = find_column_view(scrolled_window.upcast_ref()) &&
+       let Some(model) = column_view.model() &&
+       let Ok(selection) = model.downcast::<MultiSelection>()
+    {
+        let mask = Bitset::new_range(0, selection.n_items());
+        selection.set_selection(&selected, &mask);
+    }
 
-            // Reapply scroll position
-            let widget = widget_by_name(
-                "content", // TODO
-                window.upcast_ref::<gtk4::Widget>(),
-            ).ok_or("Missing widget named 'content'")?;
+    // Tabs in the background have no size to scroll in until they're shown
+    let scroll = Rc::new(Cell::new(Some((state.h_value, state.v_value))));
+    let scrolled_window_weak = scrolled_window.downgrade();
 
-            let scrolled_window = widget.downcast::<gtk4::ScrolledWindow>()
-                .map_err(|w|
-                    format!(
-                        "Expected ScrolledWindow, but got {}",
-                        w.type_().name()
-                    )
-                )?;
+    let restore = move || {
+        let Some((h_value, v_value)) = scroll.take() else {
+            return;
+        };
+
+        let scrolled_window_weak = scrolled_window_weak.clone();
 
-            gtk4::glib::idle_add_local_once(move || {
+        gtk4::glib::idle_add_local_once(move || {
+            if let Some(scrolled_window) = scrolled_window_weak.upgrade() {
                 scrolled_window.hadjustment().set_value(h_value);
                 scrolled_window.vadjustment().set_value(v_value);
-            });
+            }
+        });
+    };
 
-            return Ok(());
-        }
+    if scrolled_window.is_mapped() {
+        restore();
+    } else {
+        scrolled_window.connect_map(move |_| restore());
     }
 
     Ok(())
 }
 
 
+/// Highlights rows changed since the last reload, and sums them up
+fn window_show_changes(
+    window: &Window,
+    db: &Database,
+    scrolled_window: &ScrolledWindow,
+    previous: Fingerprints,
+) -> Result<(), Box<dyn Error>>
+{
+    let model = find_cache_model(scrolled_window.upcast_ref())
+        .ok_or("Missing DatabaseCacheModel")?;
+
+    let (_, table) = model.database_and_table();
+
+    if previous.table_name != table.name() {
+        return Ok(());
+    }
+
+    let changes = db.changes_since(&table, &previous)?;
+    model.set_previous(previous);
+
+    // Only sum up the tab that's shown
+    if window_content(window)? != *scrolled_window {
+        return Ok(());
+    }
+
+    let counts = [
+        (changes.inserted, "new"),
+        (changes.modified, "changed"),
+        (changes.deleted, "deleted"),
+    ];
+
+    let summary = counts
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, what)| format!("{count} {what}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if summary.is_empty() {
+        return Ok(());
+    }
+
+    let overlay = widget_by_name(
+        "overlay",
+        window.upcast_ref::<Widget>(),
+    ).and_then(|w| w.downcast::<ToastOverlay>().ok())
+    .ok_or("Missing widget named 'overlay'")?;
+
+    overlay.add_toast(
+        Toast::builder()
+            .title(format!("Rows since last reload: {summary}"))
+            .timeout(4)
+            .build()
+    );
+
+    Ok(())
+}
+
+
+/// The content of the selected tab
+fn window_content(window: &Window) -> Result<ScrolledWindow, Box<dyn Error>> {
+    let page = find_tab_view(window.upcast_ref())
+        .and_then(|tab_view| tab_view.selected_page())
+        .ok_or("Missing selected tab")?;
+
+    let scrolled_window = page.child().downcast::<ScrolledWindow>()
+        .map_err(|w|
+            format!(
+                "Expected ScrolledWindow, but got {}",
+                w.type_().name()
+            )
+        )?;
+
+    Ok(scrolled_window)
+}
+
+
+pub fn window_retry(
+    window: &Window,
+    file: &File,
+) -> Result<(), Box<dyn Error>>
+{
+    match Database::from_file(file, row_order_from_settings(), &cache_snapshots()) {
+        Ok(db) => window_show_content_state(window, &db, None),
+        Err(e) => window_show_error_state(window, file, e),
+    }
+}
+
+
 fn row_order_from_settings() -> Option<RowOrder> {
     let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
 
@@ -191,6 +667,7 @@ pub fn window_new(
 
     let header = HeaderBar::new();
     header.set_widget_name("header_bar");
+    header.set_show_back_button(false); // The sidebar is hidden instead
     header.pack_end(&main_menu_new(application));
 
     let toolbar_view = ToolbarView::new();
@@ -199,9 +676,30 @@ pub fn window_new(
     toolbar_view.set_top_bar_style(ToolbarStyle::Flat);
     window.add_css_class("flat");
 
-    window.set_content(Some(&toolbar_view));
+    let split_view = NavigationSplitView::builder()
+        .sidebar(&NavigationPage::builder().title("Tables").build())
+        .content(&NavigationPage::new(&toolbar_view, "Bobby"))
+        .collapsed(true)
+        .show_content(true)
+        .build();
+
+    split_view.set_widget_name("split_view");
+
+    window.set_content(Some(&split_view));
     window.add_controller(drop_target_new(&window));
+
+    let application_handle = application.clone();
+
+    window.connect_close_request(move |window| {
+        session_save(&application_handle, Some(window.upcast_ref()));
+        Propagation::Proceed
+    });
     window.add_action(&close_action(&window));
+    window.add_action(&close_tab_action(&window));
+    window.add_action(&go_back_action(&window));
+    window.add_action(&go_forward_action(&window));
+    window.add_action(&palette_action(&window));
+    window.add_action(&toggle_sidebar_action(&window));
 
 
     let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
@@ -218,8 +716,11 @@ pub fn window_new(
 
     match file {
         Some(f) =>
-            match Database::from_file(f, row_order) {
-                Ok(db) => window_show_content_state(&window, &db, table_name)?,
+            match Database::from_file(f, row_order, &cache_snapshots()) {
+                Ok(db) => {
+                    window_show_content_state(&window, &db, table_name)?;
+                    window_recent_add(&window);
+                },
                 Err(e) => window_show_error_state(&window, f, e)?,
             },
         None => window_show_empty_state(&window)?,
@@ -239,11 +740,18 @@ pub fn window_show_empty_state(
 {
     let title = "Bobby".to_string();
 
+    let layout = gtk4::Box::new(Orientation::Vertical, 0);
+    layout.append(&button_open_new(window));
+
+    if let Some(app) = window.application() {
+        layout.append(&recent_list_new(&app));
+    }
+
     let page = StatusPage::builder()
         .icon_name("studio.planetpeanut.Bobby-symbolic")
         .title("Browse Databases")
         .description("Drag and drop <b>SQLite files</b> here")
-        .child(&button_open_new(window))
+        .child(&layout)
         .hexpand(true)
         .vexpand(true)
         .build();
@@ -259,7 +767,7 @@ pub fn window_show_empty_state(
 fn window_show_error_state(
     window: &Window,
     file: &File,
-    error: Box<dyn Error>,
+    error: DatabaseError,
 ) -> Result<(), Box<dyn Error>>
 {
     let title = file
@@ -270,11 +778,35 @@ fn window_show_error_state(
         .to_string_lossy()
         .to_string();
 
+    let buttons = gtk4::Box::builder()
+        .orientation(Orientation::Vertical)
+        .spacing(12)
+        .halign(Align::Center)
+        .build();
+
+    // Offer the way out that fits the problem best
+    match &error {
+        DatabaseError::Locked(_, _) => {
+            buttons.append(&button_recovery_new("Try Again", "win.retry"));
+            buttons.append(&button_recovery_new("Open a Copy", "win.open-copy"));
+        },
+        DatabaseError::Corrupt(_, _) => {
+            buttons.append(&button_recovery_new("Check Integrity", "win.check-integrity"));
+            buttons.append(&button_recovery_new("Open a Copy", "win.open-copy"));
+        },
+        e if e.is_transient() => {
+            buttons.append(&button_recovery_new("Try Again", "win.retry"));
+        },
+        _ => {},
+    }
+
+    buttons.append(&button_open_new(window));
+
     let page = StatusPage::builder()
         .icon_name("dialog-error-symbolic")
-        .title("Unable to Open File")
-        .description(error.to_string())
-        .child(&button_open_new(window))
+        .title(error.title())
+        .description(gtk4::glib::markup_escape_text(&error.to_string()))
+        .child(&buttons)
         .hexpand(true)
         .vexpand(true)
         .build();
@@ -289,10 +821,32 @@ fn window_show_error_state(
     window.set_widget_name(&path);
     window_set_child(window, &page)?;
 
+    let window = window.downcast_ref::<ApplicationWindow>()
+        .ok_or("Could not cast to ApplicationWindow")?;
+
+    window.add_action(&retry_action(window, file));
+    window.add_action(&open_copy_action(window, file));
+    window.add_action(&check_integrity_action(window, file));
+
     Ok(())
 }
 
 
+/// Moves the window's file to the top of recent files, as just opened.
+/// Not done when showing the content, as that also happens on reload.
+fn window_recent_add(window: &Window) {
+    let (Some(app), Some(model)) = (window.application(), find_cache_model(window.upcast_ref())) else {
+        return;
+    };
+
+    let (database, table) = model.database_and_table();
+
+    if let Some(path) = database.file.path() {
+        recent_add(&app, &path, Some(table.name()));
+    }
+}
+
+
 fn window_show_content_state(
     window: &Window,
     db: &Database,
@@ -320,19 +874,17 @@ fn window_show_content_state(
 
     let tables = db.tables()?;
 
-    let table =
-        if let Some(name) = table_name {
-            tables
-                .iter()
-                .find(|t| t.name() == name)
-                .cloned()
-                .ok_or("Table does not exist")?
-        } else {
-            tables
-                .first()
-                .cloned()
-                .ok_or("Table list empty")?
-        };
+    // Fall back to the first table when it's gone or misspelled
+    let missing_table = table_name
+        .as_ref()
+        .filter(|name| !tables.iter().any(|t| t.name() == **name));
+
+    let table = table_name
+        .as_ref()
+        .and_then(|name| tables.iter().find(|t| t.name() == *name))
+        .or(tables.first())
+        .cloned()
+        .ok_or("Table list empty")?;
 
     let table_index = tables
         .iter()
@@ -342,23 +894,52 @@ fn window_show_content_state(
 
 
     let content = content_new(db, &table)?;
+    let sidebar = sidebar_new(db, &tables);
+
+    let app_window = window.downcast_ref::<ApplicationWindow>()
+        .ok_or("Could not cast to ApplicationWindow")?;
+
+    let tab_view = tab_view_new(app_window);
+    tab_page_add(&tab_view, &table, &content);
 
 
     let banner = libadwaita::Banner::builder()
         .title("File has changed")
         .button_label("Reload")
-        .action_name("app.reload")
+        .action_name("win.reload")
         .button_style(libadwaita::BannerButtonStyle::Suggested)
-        // .revealed(true) // TODO: Reveal on file changes
         .build();
 
     let layout = gtk4::Box::new(Orientation::Vertical, 0);
     layout.append(&banner);
-    layout.append(&content);
+    layout.append(&tab_bar_new(&tab_view));
+    layout.append(&tab_view);
 
     let overlay = ToastOverlay::new();
+    overlay.set_widget_name("overlay");
     overlay.set_child(Some(&layout));
 
+    log::debug(&format!("{path}: {}", db.open_mode));
+
+    if let Some(name) = missing_table {
+        overlay.add_toast(
+            Toast::builder()
+                .title(gtk4::glib::markup_escape_text(&format!("No table named ‘{name}’")))
+                .timeout(4)
+                .build()
+        );
+    }
+
+    // WAL databases are read differently, so let people know
+    if db.open_mode != OpenMode::Immutable {
+        overlay.add_toast(
+            Toast::builder()
+                .title(db.open_mode.to_string())
+                .timeout(4)
+                .build()
+        );
+    }
+
 
     let widget = widget_by_name(
         "header_bar",
@@ -392,11 +973,24 @@ fn window_show_content_state(
         },
         None => {
             let switcher = table_switcher_new(&tables);
+            header.pack_start(&button_navigation_new());
             header.pack_start(&switcher);
             switcher
         },
     };
 
+    if widget_by_name("follow", window.upcast_ref::<Widget>()).is_none() {
+        header.pack_end(&button_follow_new());
+    }
+
+    if widget_by_name("jump", window.upcast_ref::<Widget>()).is_none() {
+        header.pack_end(&button_jump_new());
+    }
+
+    if widget_by_name("columns", window.upcast_ref::<Widget>()).is_none() {
+        header.pack_end(&button_columns_new());
+    }
+
     switcher.set_label(&table.name());
 
 
@@ -419,14 +1013,61 @@ fn window_show_content_state(
     window.set_title(Some(&title));
     window.set_widget_name(&path);
     window_set_child(window, &overlay)?;
+    window_set_sidebar(window, Some(sidebar.upcast_ref()))?;
 
-    let window = window.downcast_ref::<ApplicationWindow>()
-        .ok_or("Could not cast to ApplicationWindow")?;
+    let window = app_window;
 
+    window.add_action(&choose_columns_action(window));
+    window.add_action(&copy_column_action(window, &overlay));
+    window.add_action(&copy_link_action(window, &overlay));
     window.add_action(&copy_row_action(window, &overlay));
+    window.add_action(&copy_row_as_action(window, &overlay));
     window.add_action(&copy_val_action(window, &overlay));
+    window.add_action(&export_action(window, &overlay));
+    window.add_action(&export_database_action(window, &overlay));
+    window.add_action(&fit_column_action(window));
+    window.add_action(&hide_column_action(window));
+    window.add_action(&jump_to_row_action(window, &overlay));
     window.add_action(&reload_action(window));
-    window.add_action(&switch_table_action(window, layout, table_index, tables, switcher)); // TODO: Ugly
+
+    let list = widget_by_name("sidebar", sidebar.upcast_ref())
+        .and_then(|w| w.downcast::<ListBox>().ok())
+        .ok_or("Missing widget named 'sidebar'")?;
+
+    sidebar_select(&list, &table_index);
+
+    let switch_table = switch_table_action(window, tab_view, table_index, tables, switcher);
+
+    switch_table.connect_state_notify(move |action| {
+        if let Some(state) = action.state().and_then(|v| v.str().map(String::from)) {
+            sidebar_select(&list, &state);
+        }
+    });
+
+    window.add_action(&switch_table);
+
+    // Keep following across reloads
+    if window.lookup_action("follow").is_none() {
+        window.add_action(&follow_action(window));
+    }
+
+    // Snapshots are copies, which never get new rows
+    if let Some(follow) = window.lookup_action("follow").and_downcast::<SimpleAction>() {
+        let can_follow = db.open_mode != OpenMode::Snapshot;
+
+        if !can_follow {
+            follow.change_state(&Variant::from(false)); // Also stops polling
+        }
+
+        follow.set_enabled(can_follow);
+    }
+
+    let monitors = monitor_database(window, db, &banner)?;
+
+    // SAFETY: Replaces the monitors of the previous content
+    unsafe {
+        window.set_data("monitors", monitors);
+    }
 
     Ok(())
 }
@@ -448,44 +1089,76 @@ fn window_set_child(
         );
     }
 
+    // The sidebar belongs to the previous content
+    window_set_sidebar(window, None)
+}
+
+
+/// Puts `sidebar` next to the content, or leaves it out when `None`
+fn window_set_sidebar(
+    window: &Window,
+    sidebar: Option<&Widget>,
+) -> Result<(), Box<dyn Error>>
+{
+    let page = window_split_view(window)?
+        .sidebar()
+        .ok_or("Missing sidebar page")?;
+
+    page.set_child(sidebar);
+    window_toggle_sidebar(window)
+}
+
+
+/// Shows the sidebar when turned on and there's a database to list
+pub fn window_toggle_sidebar(window: &Window) -> Result<(), Box<dyn Error>> {
+    let split_view = window_split_view(window)?;
+    let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
+
+    let has_sidebar = split_view
+        .sidebar()
+        .and_then(|page| page.child())
+        .is_some();
+
+    split_view.set_collapsed(!(has_sidebar && settings.boolean("sidebar")));
+    split_view.set_show_content(true);
+
     Ok(())
 }
 
 
-pub fn window_toggle_row_numbers(window: &Window) -> Result<(), Box<dyn Error>> {
+fn window_split_view(window: &Window) -> Result<NavigationSplitView, Box<dyn Error>> {
     let widget = widget_by_name(
-        "content", // TODO
-        window.upcast_ref::<gtk4::Widget>(),
-    ).ok_or("Missing widget named 'content'")?;
+        "split_view",
+        window.upcast_ref::<Widget>(),
+    ).ok_or("Missing widget named 'split_view'")?;
 
-    let scrolled_window = widget.downcast::<gtk4::ScrolledWindow>()
+    let split_view = widget.downcast::<NavigationSplitView>()
         .map_err(|w|
             format!(
-                "Expected ScrolledWindow, but got {}",
+                "Expected NavigationSplitView, but got {}",
                 w.type_().name()
             )
         )?;
 
-    let widget = scrolled_window
-        .clone()
-        .child()
-        .ok_or("err")?;
-
-    let column_view = widget.downcast::<gtk4::ColumnView>()
-        .map_err(|w|
-            format!(
-                "Expected ColumnView, but got {}",
-                w.type_().name()
-            )
-        )?;
+    Ok(split_view)
+}
 
-    content_force_redraw(&column_view);
 
+pub fn window_toggle_row_numbers(window: &Window) -> Result<(), Box<dyn Error>> {
+    let tab_view = find_tab_view(window.upcast_ref())
+        .ok_or("Missing widget named 'tabs'")?;
 
     let settings = gio::Settings::new("studio.planetpeanut.Bobby"); // TODO
 
-    if let Some(obj) = column_view.columns().item(0) {
-        if let Some(first_col) = obj.downcast_ref::<gtk4::ColumnViewColumn>() {
+    // Every tab, so they don't differ when switching
+    for page in tab_pages(&tab_view) {
+        let Some(column_view) = find_column_view(&page.child()) else {
+            continue;
+        };
+
+        content_force_redraw(&column_view);
+
+        if let Some(first_col) = view_column(&column_view, 0) {
             first_col.set_visible(
                 settings.boolean("row-numbers")
             );
@@ -526,7 +1199,5 @@ pub fn window_change_content(
         table
     )?;
 
-    // TODO: Swap the content here. Need to get the layout box somehow...
-
     Ok(content)
 }
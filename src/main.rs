@@ -25,6 +25,7 @@ pub mod bobby;
 
 use std::env::args;
 use std::error::Error;
+use std::process::exit;
 
 use crate::app::app_version;
 use crate::app::{ App, app_runs_as_root, app_runs_in_terminal };
@@ -38,9 +39,18 @@ fn main() -> Result<(), Box<dyn Error>> {
         log::error_and_exit("Cannot run as root")
     }
 
+    let args = args().collect();
+
+    // Scripts and SSH sessions may not have a terminal
+    if let Some(result) = App::default().cli_run_command(&args) {
+        match result {
+            Ok(()) => exit(0),
+            Err(e) => log::error_and_exit(&e.to_string()),
+        }
+    }
+
     if app_runs_in_terminal() {
         let mut app = App::default();
-        let args = args().collect();
 
         if let Err(e) = app.cli_parse_args(&args) {
             log::error_and_exit(&e.to_string());